@@ -0,0 +1,4 @@
+use crate::app_state::json_registry::JsonRegistry;
+use crate::utils::models::RoamingAgreement;
+
+pub type AgreementRegistry = JsonRegistry<RoamingAgreement>;
@@ -0,0 +1,82 @@
+use log::info;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{io, sync::RwLock};
+use tokio::{
+    fs::{self, OpenOptions},
+    io::AsyncWriteExt,
+    sync::Mutex,
+};
+
+pub struct AppendOnlyLog<T> {
+    file_path: Option<String>,
+    pub entries: RwLock<Vec<T>>,
+    writer: Mutex<()>,
+}
+
+impl<T: Serialize + DeserializeOwned> AppendOnlyLog<T> {
+    pub fn in_memory() -> Self {
+        AppendOnlyLog {
+            file_path: None,
+            entries: RwLock::new(Vec::new()),
+            writer: Mutex::new(()),
+        }
+    }
+
+    pub async fn open(file_path: String) -> Result<Self, String> {
+        let entries = match fs::read_to_string(&file_path).await {
+            Ok(raw) => raw
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .enumerate()
+                .map(|(line_no, line)| {
+                    serde_json::from_str::<T>(line).map_err(|e| {
+                        format!(
+                            "Failed to parse entry {} in '{}': {}",
+                            line_no + 1,
+                            file_path,
+                            e
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("Failed to read '{}': {}", file_path, e)),
+        };
+
+        info!("Loaded {} entries from {}", entries.len(), file_path);
+        Ok(AppendOnlyLog {
+            file_path: Some(file_path),
+            entries: RwLock::new(entries),
+            writer: Mutex::new(()),
+        })
+    }
+
+    pub async fn append_with(&self, build_entry: impl FnOnce(&[T]) -> T) -> Result<(), io::Error> {
+        let _writer = self.writer.lock().await;
+        let entry = {
+            let entries = self
+                .entries
+                .read()
+                .map_err(|_| io::Error::other("Lock poisoned"))?;
+            build_entry(&entries)
+        };
+
+        if let Some(path) = &self.file_path {
+            let mut line = serde_json::to_string(&entry).map_err(io::Error::other)?;
+            line.push('\n');
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .await?;
+            file.write_all(line.as_bytes()).await?;
+            file.sync_data().await?;
+        }
+
+        self.entries
+            .write()
+            .map_err(|_| io::Error::other("Lock poisoned"))?
+            .push(entry);
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::app_state::append_log::AppendOnlyLog;
+use crate::utils::models::{AuditEntry, AuditOperation, Operator};
+use chrono::Utc;
+use std::io;
+
+pub type AuditLog = AppendOnlyLog<AuditEntry>;
+
+impl AppendOnlyLog<AuditEntry> {
+    pub async fn record(
+        &self,
+        actor: &str,
+        operation: AuditOperation,
+        tadig: &str,
+        before: Option<&Operator>,
+        after: Option<&Operator>,
+    ) -> Result<(), io::Error> {
+        self.append_with(|entries| AuditEntry {
+            sequence: entries.last().map_or(1, |last| last.sequence + 1),
+            timestamp: Utc::now(),
+            actor: actor.to_string(),
+            operation,
+            tadig: tadig.to_string(),
+            before: before.cloned(),
+            after: after.cloned(),
+        })
+        .await
+    }
+}
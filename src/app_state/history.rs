@@ -0,0 +1,158 @@
+use crate::app_state::append_log::AppendOnlyLog;
+use crate::utils::{
+    error_responses::ErrorResponse,
+    models::{AuditOperation, Operator, OperatorKey, OperatorVersion},
+};
+use chrono::{DateTime, Utc};
+use std::{collections::BTreeMap, io};
+
+pub type OperatorHistory = AppendOnlyLog<OperatorVersion>;
+
+fn latest_per_lineage(entries: &[OperatorVersion]) -> BTreeMap<u64, &OperatorVersion> {
+    entries.iter().map(|entry| (entry.lineage, entry)).collect()
+}
+
+fn find_live_lineage(entries: &[OperatorVersion], key: OperatorKey<'_>) -> Option<u64> {
+    latest_per_lineage(entries)
+        .into_values()
+        .find(|latest| latest.operator.as_ref().is_some_and(|op| key.matches(op)))
+        .map(|latest| latest.lineage)
+}
+
+fn same_content(a: &Operator, b: &Operator) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+impl AppendOnlyLog<OperatorVersion> {
+    pub fn live_lineage<'k>(
+        &self,
+        key: impl Into<OperatorKey<'k>>,
+    ) -> Result<Option<u64>, ErrorResponse> {
+        let entries = self
+            .entries
+            .read()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        Ok(find_live_lineage(&entries, key.into()))
+    }
+
+    pub async fn record(
+        &self,
+        operation: AuditOperation,
+        lineage: Option<u64>,
+        operator: Option<&Operator>,
+    ) -> Result<(), io::Error> {
+        self.append_with(|entries| {
+            let lineage = lineage.unwrap_or_else(|| {
+                entries
+                    .iter()
+                    .map(|e| e.lineage)
+                    .max()
+                    .map_or(1, |max| max + 1)
+            });
+            let version = entries.iter().filter(|e| e.lineage == lineage).count() as u32 + 1;
+            OperatorVersion {
+                lineage,
+                version,
+                timestamp: Utc::now(),
+                operation,
+                operator: operator.cloned(),
+            }
+        })
+        .await
+    }
+
+    pub async fn reconcile(&self, operators: &[Operator]) -> Result<(), io::Error> {
+        for op in operators {
+            let (lineage, unchanged) = {
+                let entries = self
+                    .entries
+                    .read()
+                    .map_err(|_| io::Error::other("Lock poisoned"))?;
+                let lineage = find_live_lineage(&entries, op.primary_tadig().into());
+                let unchanged = lineage
+                    .and_then(|l| latest_per_lineage(&entries).get(&l).copied().cloned())
+                    .and_then(|latest| latest.operator)
+                    .is_some_and(|latest| same_content(&latest, op));
+                (lineage, unchanged)
+            };
+            if !unchanged {
+                self.record(AuditOperation::Baseline, lineage, Some(op))
+                    .await?;
+            }
+        }
+
+        let vanished: Vec<u64> = {
+            let entries = self
+                .entries
+                .read()
+                .map_err(|_| io::Error::other("Lock poisoned"))?;
+            latest_per_lineage(&entries)
+                .into_values()
+                .filter(|latest| {
+                    latest.operator.as_ref().is_some_and(|recorded| {
+                        !operators
+                            .iter()
+                            .any(|op| op.has_tadig(recorded.primary_tadig()))
+                    })
+                })
+                .map(|latest| latest.lineage)
+                .collect()
+        };
+        for lineage in vanished {
+            self.record(AuditOperation::Baseline, Some(lineage), None)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub fn versions_for<'k>(
+        &self,
+        key: impl Into<OperatorKey<'k>>,
+    ) -> Result<Vec<OperatorVersion>, ErrorResponse> {
+        let key = key.into();
+        let entries = self
+            .entries
+            .read()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        let lineage = find_live_lineage(&entries, key).or_else(|| {
+            entries
+                .iter()
+                .rev()
+                .find(|e| e.operator.as_ref().is_some_and(|op| key.matches(op)))
+                .map(|e| e.lineage)
+        });
+        Ok(entries
+            .iter()
+            .filter(|e| Some(e.lineage) == lineage)
+            .cloned()
+            .collect())
+    }
+
+    pub fn max_operator_id(&self) -> Result<u64, ErrorResponse> {
+        let entries = self
+            .entries
+            .read()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        Ok(entries
+            .iter()
+            .filter_map(|e| e.operator.as_ref().map(|op| op.id))
+            .max()
+            .unwrap_or(0))
+    }
+
+    pub fn snapshot_as_of(&self, as_of: DateTime<Utc>) -> Result<Vec<Operator>, ErrorResponse> {
+        let entries = self
+            .entries
+            .read()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        let visible: Vec<OperatorVersion> = entries
+            .iter()
+            .filter(|e| e.timestamp <= as_of)
+            .cloned()
+            .collect();
+        Ok(latest_per_lineage(&visible)
+            .into_values()
+            .filter_map(|latest| latest.operator.clone())
+            .collect())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::utils::error_responses::ErrorResponse;
+use log::info;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{io, sync::RwLock};
+use tokio::{fs, sync::Mutex};
+
+pub struct JsonRegistry<T> {
+    file_path: Option<String>,
+    pub entries: RwLock<Vec<T>>,
+    writer: Mutex<()>,
+}
+
+impl<T: Serialize + DeserializeOwned + Clone> JsonRegistry<T> {
+    pub fn in_memory() -> Self {
+        JsonRegistry {
+            file_path: None,
+            entries: RwLock::new(Vec::new()),
+            writer: Mutex::new(()),
+        }
+    }
+
+    pub async fn open(file_path: String) -> Result<Self, String> {
+        let entries: Vec<T> = match fs::read_to_string(&file_path).await {
+            Ok(raw) => serde_json::from_str(&raw)
+                .map_err(|e| format!("Failed to parse '{}': {}", file_path, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("Failed to read '{}': {}", file_path, e)),
+        };
+
+        info!("Loaded {} entries from {}", entries.len(), file_path);
+        Ok(JsonRegistry {
+            file_path: Some(file_path),
+            entries: RwLock::new(entries),
+            writer: Mutex::new(()),
+        })
+    }
+
+    async fn write_file(path: &str, entries: &[T]) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(entries).map_err(io::Error::other)?;
+        let tmp = format!("{}.tmp", path);
+        fs::write(&tmp, &json).await?;
+        fs::File::open(&tmp).await?.sync_all().await?;
+        fs::rename(&tmp, path).await
+    }
+
+    pub async fn commit<R>(
+        &self,
+        change: impl FnOnce(&mut Vec<T>) -> Result<R, ErrorResponse>,
+    ) -> Result<R, ErrorResponse> {
+        let _writer = self.writer.lock().await;
+        let mut updated = self
+            .entries
+            .read()
+            .map_err(|_| ErrorResponse::InternalError)?
+            .clone();
+        let outcome = change(&mut updated)?;
+
+        if let Some(path) = &self.file_path {
+            Self::write_file(path, &updated).await.map_err(|e| {
+                log::error!("Failed to write {}: {}", path, e);
+                ErrorResponse::InternalError
+            })?;
+        }
+
+        *self
+            .entries
+            .write()
+            .map_err(|_| ErrorResponse::InternalError)? = updated;
+        Ok(outcome)
+    }
+}
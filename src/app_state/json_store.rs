@@ -0,0 +1,214 @@
+use crate::app_state::store::OperatorStore;
+use crate::utils::models::Operator;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum JournalEntry {
+    Insert { operator: Operator },
+    InsertBatch { operators: Vec<Operator> },
+    Update { operator: Operator },
+    Delete { id: u64 },
+}
+
+fn upsert(operators: &mut Vec<Operator>, operator: Operator) {
+    match operators.iter_mut().find(|op| op.id == operator.id) {
+        Some(stored) => *stored = operator,
+        None => operators.push(operator),
+    }
+}
+
+impl JournalEntry {
+    fn apply(self, operators: &mut Vec<Operator>) {
+        match self {
+            JournalEntry::Insert { operator } | JournalEntry::Update { operator } => {
+                upsert(operators, operator)
+            }
+            JournalEntry::InsertBatch { operators: batch } => {
+                for operator in batch {
+                    upsert(operators, operator);
+                }
+            }
+            JournalEntry::Delete { id } => operators.retain(|op| op.id != id),
+        }
+    }
+}
+
+pub struct JsonFileStore {
+    file_path: String,
+    journal_path: String,
+    writer: Mutex<()>,
+    snapshot_written_at: Mutex<Option<SystemTime>>,
+}
+
+impl JsonFileStore {
+    pub fn new(file_path: impl Into<String>) -> Self {
+        let file_path = file_path.into();
+        JsonFileStore {
+            journal_path: format!("{}.journal", file_path),
+            file_path,
+            writer: Mutex::new(()),
+            snapshot_written_at: Mutex::new(None),
+        }
+    }
+
+    pub fn file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    pub fn journal_path(&self) -> &str {
+        &self.journal_path
+    }
+
+    fn read_snapshot(&self) -> io::Result<Vec<Operator>> {
+        let data = fs::read_to_string(&self.file_path)?;
+        serde_json::from_str(&data).map_err(io::Error::other)
+    }
+
+    fn read_journal(&self) -> io::Result<Vec<JournalEntry>> {
+        let raw = match fs::read_to_string(&self.journal_path) {
+            Ok(raw) => raw,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = Vec::new();
+        for (line_no, line) in raw.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(line) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => {
+                    warn!(
+                        "Ignoring journal '{}' from line {} onwards: {}",
+                        self.journal_path,
+                        line_no + 1,
+                        e
+                    );
+                    break;
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    fn replay(&self) -> io::Result<Vec<Operator>> {
+        let mut operators = self.read_snapshot()?;
+        let journal = self.read_journal()?;
+        if !journal.is_empty() {
+            info!(
+                "Replaying {} journal entries from {}",
+                journal.len(),
+                self.journal_path
+            );
+        }
+        for entry in journal {
+            entry.apply(&mut operators);
+        }
+        Ok(operators)
+    }
+
+    fn append(&self, entry: &JournalEntry) -> io::Result<()> {
+        let mut line = serde_json::to_string(entry).map_err(io::Error::other)?;
+        line.push('\n');
+
+        let _guard = self
+            .writer
+            .lock()
+            .map_err(|_| io::Error::other("Lock poisoned"))?;
+        let mut journal = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.journal_path)?;
+        journal.write_all(line.as_bytes())?;
+        journal.sync_data()
+    }
+
+    fn write_snapshot(&self, operators: &[Operator]) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(operators).map_err(io::Error::other)?;
+        let tmp = format!("{}.tmp", self.file_path);
+        fs::write(&tmp, &json)?;
+        fs::File::open(&tmp)?.sync_all()?;
+        fs::rename(&tmp, &self.file_path)?;
+        if let Ok(mut written_at) = self.snapshot_written_at.lock() {
+            *written_at = fs::metadata(&self.file_path)?.modified().ok();
+        }
+        match fs::remove_file(&self.journal_path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OperatorStore for JsonFileStore {
+    fn load(&self) -> io::Result<Vec<Operator>> {
+        self.replay()
+    }
+
+    fn get(&self, id: u64) -> io::Result<Option<Operator>> {
+        Ok(self.replay()?.into_iter().find(|op| op.id == id))
+    }
+
+    fn insert(&self, operator: &Operator) -> io::Result<()> {
+        self.append(&JournalEntry::Insert {
+            operator: operator.clone(),
+        })
+    }
+
+    fn insert_batch(&self, operators: &[Operator]) -> io::Result<()> {
+        self.append(&JournalEntry::InsertBatch {
+            operators: operators.to_vec(),
+        })
+    }
+
+    fn update(&self, operator: &Operator) -> io::Result<()> {
+        self.append(&JournalEntry::Update {
+            operator: operator.clone(),
+        })
+    }
+
+    fn delete(&self, id: u64) -> io::Result<()> {
+        self.append(&JournalEntry::Delete { id })
+    }
+
+    fn snapshot(&self, operators: &[Operator]) -> io::Result<()> {
+        let _guard = self
+            .writer
+            .lock()
+            .map_err(|_| io::Error::other("Lock poisoned"))?;
+        self.write_snapshot(operators)
+    }
+
+    fn compact(&self) -> io::Result<()> {
+        let _guard = self
+            .writer
+            .lock()
+            .map_err(|_| io::Error::other("Lock poisoned"))?;
+        if fs::metadata(&self.journal_path).is_err() {
+            return Ok(());
+        }
+        let operators = self.replay()?;
+        self.write_snapshot(&operators)?;
+        info!(
+            "Compacted journal into {} ({} operators)",
+            self.file_path,
+            operators.len()
+        );
+        Ok(())
+    }
+
+    fn snapshot_written_at(&self) -> Option<SystemTime> {
+        self.snapshot_written_at
+            .lock()
+            .ok()
+            .and_then(|written_at| *written_at)
+    }
+}
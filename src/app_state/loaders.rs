@@ -1,45 +1,108 @@
-use crate::app_state::model::{AppState, CountryBordersMap};
-use crate::utils::models::Operator;
+use crate::app_state::agreements::AgreementRegistry;
+use crate::app_state::audit::AuditLog;
+use crate::app_state::history::OperatorHistory;
+use crate::app_state::json_store::JsonFileStore;
+use crate::app_state::model::{AppState, CountryBordersMap, backfill_operator_ids};
+use crate::app_state::sqlite_store::SqliteStore;
+use crate::app_state::steering::SteeringRegistry;
+use crate::app_state::store::OperatorStore;
+use crate::utils::config::{self, StorageBackend};
+use crate::utils::models::{BorderCountry, Operator};
 use csv::StringRecord;
 use log::{info, warn};
-use std::{collections::HashMap, env};
+use std::{collections::HashMap, env, io, sync::Arc};
 use tokio::fs;
 
-pub async fn load_operator_mappings() -> Result<AppState, String> {
-    let path = env::var("OPERATOR_MAPPINGS_FILE_PATH").unwrap_or_else(|e| {
+pub fn operator_mappings_path() -> String {
+    env::var("OPERATOR_MAPPINGS_FILE_PATH").unwrap_or_else(|e| {
         let default = "./resources/operator_mappings.json".to_string();
         warn!(
             "OPERATOR_MAPPINGS_FILE_PATH not set ({:?}), defaulting to {}",
             e, default
         );
         default
-    });
-
-    let data = fs::read_to_string(&path)
-        .await
-        .map_err(|e| format!("Failed to read '{}': {}", path, e))?;
-
-    let list: Vec<Operator> =
-        serde_json::from_str(&data).map_err(|e| format!("Failed to parse JSON: {}", e))?;
-
-    info!("Loaded {} operators from {}", list.len(), path);
-    Ok(AppState::new(list, path, HashMap::new()))
+    })
 }
 
-pub async fn load_country_borders() -> Result<CountryBordersMap, String> {
-    let path = env::var("COUNTRY_BORDERS_CSV_FILE_PATH").unwrap_or_else(|e| {
+pub fn country_borders_path() -> String {
+    env::var("COUNTRY_BORDERS_CSV_FILE_PATH").unwrap_or_else(|e| {
         let default = "./resources/country_borders.csv".to_string();
         warn!(
             "COUNTRY_BORDERS_CSV_FILE_PATH not set ({:?}), defaulting to {}",
             e, default
         );
         default
-    });
+    })
+}
+
+pub async fn load_operator_mappings() -> Result<AppState, String> {
+    let path = operator_mappings_path();
+
+    let json_store = JsonFileStore::new(path.clone());
+    let cfg = config::load()?;
+    let (store, mut list, seeded): (Arc<dyn OperatorStore>, Vec<Operator>, bool) =
+        match cfg.storage_backend {
+            StorageBackend::Json => {
+                let list = json_store
+                    .load()
+                    .map_err(|e| format!("Failed to load '{}': {}", path, e))?;
+                (Arc::new(json_store), list, false)
+            }
+            StorageBackend::Sqlite => {
+                let sqlite = SqliteStore::open(&cfg.sqlite_database_path)
+                    .map_err(|e| format!("Failed to open '{}': {}", cfg.sqlite_database_path, e))?;
+                let list = sqlite
+                    .load()
+                    .map_err(|e| format!("Failed to load '{}': {}", cfg.sqlite_database_path, e))?;
+                if list.is_empty() {
+                    let seed = match json_store.load() {
+                        Ok(seed) => seed,
+                        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+                        Err(e) => return Err(format!("Failed to load '{}': {}", path, e)),
+                    };
+                    info!(
+                        "Seeding {} with {} operators from {}",
+                        cfg.sqlite_database_path,
+                        seed.len(),
+                        path
+                    );
+                    (Arc::new(sqlite), seed, true)
+                } else {
+                    (Arc::new(sqlite), list, false)
+                }
+            }
+        };
+
+    let backfilled = backfill_operator_ids(&mut list);
+    if backfilled > 0 {
+        info!("Assigned ids to {} operators without one", backfilled);
+    }
+    if backfilled > 0 || seeded {
+        store
+            .snapshot(&list)
+            .map_err(|e| format!("Failed to write backfilled operators: {}", e))?;
+    } else {
+        store
+            .compact()
+            .map_err(|e| format!("Failed to compact recovered journal: {}", e))?;
+    }
+    info!(
+        "Loaded {} operators from {:?} store",
+        list.len(),
+        cfg.storage_backend
+    );
+    Ok(AppState::with_store(list, path, HashMap::new(), store))
+}
 
+pub async fn load_country_borders() -> Result<CountryBordersMap, String> {
+    let path = country_borders_path();
     let raw_csv = fs::read_to_string(&path)
         .await
         .map_err(|e| format!("Failed to read '{}': {}", path, e))?;
+    parse_country_borders(&raw_csv)
+}
 
+pub fn parse_country_borders(raw_csv: &str) -> Result<CountryBordersMap, String> {
     let mut rdr = csv::ReaderBuilder::new()
         .has_headers(true)
         .from_reader(raw_csv.as_bytes());
@@ -51,13 +114,72 @@ pub async fn load_country_borders() -> Result<CountryBordersMap, String> {
         if record.len() < 4 {
             continue;
         }
-        let base_country = record[1].trim().to_string();
-        let border_country = record[3].trim().to_string();
-        exact_borders
-            .entry(base_country)
-            .or_default()
-            .push(border_country);
+        let base_iso2 = record[0].trim().to_ascii_uppercase();
+        if base_iso2.is_empty() {
+            continue;
+        }
+        let neighbors = exact_borders.entry(base_iso2).or_default();
+        let border_iso2 = record[2].trim().to_ascii_uppercase();
+        if border_iso2.is_empty() {
+            continue;
+        }
+        neighbors.push(BorderCountry {
+            iso2: border_iso2,
+            name: record[3].trim().to_string(),
+        });
     }
 
     Ok(exact_borders)
 }
+
+pub async fn load_audit_log() -> Result<AuditLog, String> {
+    let path = env::var("AUDIT_LOG_FILE_PATH").unwrap_or_else(|e| {
+        let default = "./resources/audit_log.jsonl".to_string();
+        warn!(
+            "AUDIT_LOG_FILE_PATH not set ({:?}), defaulting to {}",
+            e, default
+        );
+        default
+    });
+
+    AuditLog::open(path).await
+}
+
+pub async fn load_operator_history() -> Result<OperatorHistory, String> {
+    let path = env::var("OPERATOR_HISTORY_FILE_PATH").unwrap_or_else(|e| {
+        let default = "./resources/operator_history.jsonl".to_string();
+        warn!(
+            "OPERATOR_HISTORY_FILE_PATH not set ({:?}), defaulting to {}",
+            e, default
+        );
+        default
+    });
+
+    OperatorHistory::open(path).await
+}
+
+pub async fn load_roaming_agreements() -> Result<AgreementRegistry, String> {
+    let path = env::var("ROAMING_AGREEMENTS_FILE_PATH").unwrap_or_else(|e| {
+        let default = "./resources/roaming_agreements.json".to_string();
+        warn!(
+            "ROAMING_AGREEMENTS_FILE_PATH not set ({:?}), defaulting to {}",
+            e, default
+        );
+        default
+    });
+
+    AgreementRegistry::open(path).await
+}
+
+pub async fn load_steering_preferences() -> Result<SteeringRegistry, String> {
+    let path = env::var("STEERING_PREFERENCES_FILE_PATH").unwrap_or_else(|e| {
+        let default = "./resources/steering_preferences.json".to_string();
+        warn!(
+            "STEERING_PREFERENCES_FILE_PATH not set ({:?}), defaulting to {}",
+            e, default
+        );
+        default
+    });
+
+    SteeringRegistry::open(path).await
+}
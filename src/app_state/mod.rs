@@ -1,6 +1,18 @@
+pub mod agreements;
+pub mod append_log;
+pub mod audit;
+pub mod history;
+pub mod json_registry;
+pub mod json_store;
 pub mod loaders;
 pub mod model;
 pub mod persistence;
+pub mod prefix_index;
+pub mod reload;
+pub mod search_index;
+pub mod sqlite_store;
+pub mod steering;
+pub mod store;
 pub use loaders::load_operator_mappings;
 pub use model::AppState;
 pub use persistence::spawn_persistence_tasks;
@@ -1,13 +1,46 @@
-use crate::utils::models::Operator;
+use crate::app_state::{
+    agreements::AgreementRegistry, audit::AuditLog, history::OperatorHistory,
+    json_store::JsonFileStore, prefix_index::PrefixIndex, search_index::SearchIndex,
+    steering::SteeringRegistry, store::OperatorStore,
+};
+use crate::utils::{
+    config::IntegrityMode,
+    error_responses::ErrorResponse,
+    models::{BorderCountry, Operator},
+};
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::{
+    Arc, RwLock,
+    atomic::{AtomicU64, Ordering},
+};
+use tokio::sync::{Mutex, MutexGuard};
 
-pub type CountryBordersMap = HashMap<String, Vec<String>>;
+pub type CountryBordersMap = HashMap<String, Vec<BorderCountry>>;
+
+pub fn backfill_operator_ids(operators: &mut [Operator]) -> usize {
+    let first_free_id = operators.iter().map(|op| op.id).max().unwrap_or(0) + 1;
+    operators
+        .iter_mut()
+        .filter(|op| op.id == 0)
+        .zip(first_free_id..)
+        .map(|(op, id)| op.id = id)
+        .count()
+}
 
 pub struct AppState {
     pub operators: RwLock<Vec<Arc<Operator>>>,
+    pub prefix_index: RwLock<PrefixIndex>,
+    pub search_index: RwLock<SearchIndex>,
     pub mappings_file_path: String,
-    pub borders: CountryBordersMap,
+    pub borders: RwLock<CountryBordersMap>,
+    pub audit_log: AuditLog,
+    pub history: OperatorHistory,
+    pub agreements: AgreementRegistry,
+    pub steering: SteeringRegistry,
+    pub store: Arc<dyn OperatorStore>,
+    pub e164_validation_mode: IntegrityMode,
+    next_operator_id: AtomicU64,
+    store_order: Mutex<()>,
 }
 
 impl AppState {
@@ -16,11 +49,72 @@ impl AppState {
         mappings_file_path: String,
         borders_map: CountryBordersMap,
     ) -> Self {
-        let operators = initial_operators.into_iter().map(Arc::new).collect();
+        let store = Arc::new(JsonFileStore::new(mappings_file_path.clone()));
+        Self::with_store(initial_operators, mappings_file_path, borders_map, store)
+    }
+
+    pub fn with_store(
+        mut initial_operators: Vec<Operator>,
+        mappings_file_path: String,
+        borders_map: CountryBordersMap,
+        store: Arc<dyn OperatorStore>,
+    ) -> Self {
+        backfill_operator_ids(&mut initial_operators);
+        let next_operator_id = initial_operators.iter().map(|op| op.id).max().unwrap_or(0) + 1;
+        let operators: Vec<Arc<Operator>> = initial_operators.into_iter().map(Arc::new).collect();
+        let prefix_index = PrefixIndex::build(&operators);
+        let search_index = SearchIndex::build(&operators);
         AppState {
             operators: RwLock::new(operators),
+            prefix_index: RwLock::new(prefix_index),
+            search_index: RwLock::new(search_index),
             mappings_file_path,
-            borders: borders_map,
+            borders: RwLock::new(borders_map),
+            audit_log: AuditLog::in_memory(),
+            history: OperatorHistory::in_memory(),
+            agreements: AgreementRegistry::in_memory(),
+            steering: SteeringRegistry::in_memory(),
+            store,
+            e164_validation_mode: IntegrityMode::Lenient,
+            next_operator_id: AtomicU64::new(next_operator_id),
+            store_order: Mutex::new(()),
+        }
+    }
+
+    pub async fn order_store_writes(&self) -> MutexGuard<'_, ()> {
+        self.store_order.lock().await
+    }
+
+    pub fn allocate_operator_id(&self) -> u64 {
+        self.next_operator_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub fn assign_missing_ids(&self, operators: &mut [Operator]) -> usize {
+        if let Some(highest) = operators.iter().map(|op| op.id).max() {
+            self.reserve_operator_ids_through(highest);
         }
+        operators
+            .iter_mut()
+            .filter(|op| op.id == 0)
+            .map(|op| op.id = self.allocate_operator_id())
+            .count()
+    }
+
+    pub fn reserve_operator_ids_through(&self, id: u64) {
+        self.next_operator_id.fetch_max(id + 1, Ordering::Relaxed);
+    }
+
+    pub fn rebuild_indexes(&self, operators: &[Arc<Operator>]) -> Result<(), ErrorResponse> {
+        let mut index_guard = self
+            .prefix_index
+            .write()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        *index_guard = PrefixIndex::build(operators);
+        let mut search_guard = self
+            .search_index
+            .write()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        *search_guard = SearchIndex::build(operators);
+        Ok(())
     }
 }
@@ -1,32 +1,21 @@
-use crate::app_state::model::AppState;
-use crate::utils::models::Operator;
+use crate::app_state::{model::AppState, store::OperatorStore};
+use crate::utils::{error_responses::ErrorResponse, models::Operator};
 use actix_web::{dev::ServerHandle, web::Data};
-use std::{env, time::Duration};
-use tokio::{fs, task, time};
+use std::{io, sync::Arc, time::Duration};
+use tokio::{task, time};
 
 pub fn spawn_persistence_tasks(
     shared_state: Data<AppState>,
     interval_minutes: u64,
     server_handle: ServerHandle,
 ) {
-    let path = env::var("OPERATOR_MAPPINGS_FILE_PATH").unwrap_or_else(|e| {
-        let default_path = "./resources/operator_mappings.json".to_string();
-        log::warn!(
-            "OPERATOR_MAPPINGS_FILE_PATH not set ({:?}), defaulting to {}",
-            e,
-            default_path
-        );
-        default_path
-    });
-
     {
         let state = shared_state.clone();
-        let path = path.clone();
         task::spawn(async move {
             let mut ticker = time::interval(Duration::from_secs(interval_minutes * 60));
             loop {
                 ticker.tick().await;
-                if let Err(e) = save_to_disk(&state, &path).await {
+                if let Err(e) = compact_store(&state).await {
                     log::error!("Periodic save failed: {}", e);
                 }
             }
@@ -34,33 +23,72 @@ pub fn spawn_persistence_tasks(
     }
     {
         let state = shared_state.clone();
-        let path = path.clone();
         task::spawn(async move {
             let _ = tokio::signal::ctrl_c().await;
-            let _ = save_to_disk(&state, &path).await;
+            let _ = compact_store(&state).await;
             server_handle.stop(true).await;
         });
     }
 }
 
-pub(crate) async fn save_to_disk(state: &AppState, file_path: &str) -> Result<(), std::io::Error> {
-    let snapshot: Vec<Operator> = {
-        let guard = state
-            .operators
-            .read()
-            .map_err(|_| std::io::Error::new(std::io::ErrorKind::Other, "Lock poisoned"))?;
-        guard.iter().map(|arc_op| (**arc_op).clone()).collect()
-    };
+async fn run_store<F>(state: &AppState, action: F) -> io::Result<()>
+where
+    F: FnOnce(&dyn OperatorStore) -> io::Result<()> + Send + 'static,
+{
+    let store = Arc::clone(&state.store);
+    task::spawn_blocking(move || action(store.as_ref()))
+        .await
+        .map_err(io::Error::other)?
+}
+
+pub(crate) fn roll_back(
+    state: &AppState,
+    error: io::Error,
+    added: &[Arc<Operator>],
+    replaced: &[Arc<Operator>],
+) -> ErrorResponse {
+    log::error!(
+        "Store write failed, undoing the in-memory change: {}",
+        error
+    );
+    if let Ok(mut operators) = state.operators.write() {
+        operators.retain(|op| !added.iter().any(|new_op| new_op.id == op.id));
+        for previous in replaced {
+            match operators.iter_mut().find(|op| op.id == previous.id) {
+                Some(current) => *current = Arc::clone(previous),
+                None => operators.push(Arc::clone(previous)),
+            }
+        }
+        if let Err(e) = state.rebuild_indexes(&operators) {
+            return e;
+        }
+    }
+    ErrorResponse::InternalError
+}
+
+pub(crate) async fn persist_insert(state: &AppState, operator: &Operator) -> io::Result<()> {
+    let operator = operator.clone();
+    run_store(state, move |store| store.insert(&operator)).await
+}
+
+pub(crate) async fn persist_insert_batch(
+    state: &AppState,
+    operators: &[Arc<Operator>],
+) -> io::Result<()> {
+    let operators: Vec<Operator> = operators.iter().map(|op| (**op).clone()).collect();
+    run_store(state, move |store| store.insert_batch(&operators)).await
+}
 
-    let json = serde_json::to_string_pretty(&snapshot).unwrap_or_else(|e| {
-        log::error!("Failed to serialize operator list: {}", e);
-        "[]".to_string()
-    });
+pub(crate) async fn persist_update(state: &AppState, operator: &Operator) -> io::Result<()> {
+    let operator = operator.clone();
+    run_store(state, move |store| store.update(&operator)).await
+}
 
-    let tmp = format!("{}.tmp", file_path);
-    fs::write(&tmp, &json).await?;
-    fs::rename(&tmp, file_path).await?;
+pub(crate) async fn persist_delete(state: &AppState, id: u64) -> io::Result<()> {
+    run_store(state, move |store| store.delete(id)).await
+}
 
-    log::info!("State saved to {}", file_path);
-    Ok(())
+pub(crate) async fn compact_store(state: &AppState) -> io::Result<()> {
+    let _order = state.order_store_writes().await;
+    run_store(state, |store| store.compact()).await
 }
@@ -0,0 +1,78 @@
+use crate::utils::models::Operator;
+use std::sync::Arc;
+
+#[derive(Debug, Default)]
+struct TrieNode {
+    children: [Option<Box<TrieNode>>; 10],
+    subtree_last: Option<usize>,
+    terminal_last: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct PrefixTrie {
+    root: TrieNode,
+}
+
+impl PrefixTrie {
+    pub fn insert(&mut self, prefix: &str, operator_index: usize) {
+        let mut node = &mut self.root;
+        let mut depth = 0;
+        for digit in prefix.chars().map_while(|c| c.to_digit(10)) {
+            node = node.children[digit as usize].get_or_insert_with(Default::default);
+            node.subtree_last = node.subtree_last.max(Some(operator_index));
+            depth += 1;
+        }
+        if depth > 0 && depth == prefix.len() {
+            node.terminal_last = node.terminal_last.max(Some(operator_index));
+        }
+    }
+
+    pub fn longest_prefix(&self, query: &str) -> Option<(usize, usize)> {
+        let mut node = &self.root;
+        let mut best = None;
+        for (depth, digit) in query.chars().map_while(|c| c.to_digit(10)).enumerate() {
+            match node.children[digit as usize].as_deref() {
+                Some(child) => node = child,
+                None => break,
+            }
+            if let Some(index) = node.terminal_last {
+                best = Some((index, depth + 1));
+            }
+        }
+        best
+    }
+
+    pub fn longest_common_prefix(&self, query: &str) -> Option<(usize, usize)> {
+        let mut node = &self.root;
+        let mut best = None;
+        for (depth, digit) in query.chars().map_while(|c| c.to_digit(10)).enumerate() {
+            match node.children[digit as usize].as_deref() {
+                Some(child) => node = child,
+                None => break,
+            }
+            best = node.subtree_last.map(|index| (index, depth + 1));
+        }
+        best
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PrefixIndex {
+    pub e212: PrefixTrie,
+    pub e164: PrefixTrie,
+}
+
+impl PrefixIndex {
+    pub fn build(operators: &[Arc<Operator>]) -> Self {
+        let mut index = PrefixIndex::default();
+        for (position, op) in operators.iter().enumerate() {
+            for prefix in op.e212.iter().flatten() {
+                index.e212.insert(prefix, position);
+            }
+            for prefix in op.e164.iter().flatten() {
+                index.e164.insert(prefix, position);
+            }
+        }
+        index
+    }
+}
@@ -0,0 +1,183 @@
+use crate::app_state::{
+    loaders::{country_borders_path, operator_mappings_path, parse_country_borders},
+    model::{AppState, CountryBordersMap},
+};
+use crate::operators::integrity::{check_dataset_integrity, enforce_integrity, is_enforced};
+use crate::utils::{
+    config::{self, IntegrityMode},
+    error_responses::ErrorResponse,
+    models::{Operator, OperatorValidationError, ReloadSummary},
+};
+use actix_web::web::Data;
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use tokio::{fs, task, time};
+
+fn reload_failed(field: &str, message: String) -> ErrorResponse {
+    OperatorValidationError::FieldValidationError {
+        field: field.to_string(),
+        message,
+        received: None,
+    }
+    .into()
+}
+
+fn validate_reloaded_operators(
+    operators: &[Operator],
+    e164_mode: IntegrityMode,
+) -> Result<(), String> {
+    let report = check_dataset_integrity(operators);
+    let mode = config::load()?.integrity_mode;
+    if let Some(issue) = report.issues.iter().find(|issue| {
+        issue.kind.breaks_lookups()
+            || (mode == IntegrityMode::Strict && is_enforced(issue, e164_mode))
+    }) {
+        return Err(issue.message.clone());
+    }
+    enforce_integrity(&report, mode, e164_mode)
+}
+
+async fn read_operators(state: &AppState) -> Result<Vec<Operator>, ErrorResponse> {
+    let store = Arc::clone(&state.store);
+    let operators = task::spawn_blocking(move || store.load())
+        .await
+        .map_err(|_| ErrorResponse::InternalError)?
+        .map_err(|e| {
+            reload_failed(
+                "operator_mappings",
+                format!("Failed to load operator mappings: {}", e),
+            )
+        })?;
+    Ok(operators)
+}
+
+async fn read_borders(path: &str) -> Result<CountryBordersMap, ErrorResponse> {
+    let raw_csv = fs::read_to_string(path).await.map_err(|e| {
+        reload_failed(
+            "country_borders",
+            format!("Failed to read '{}': {}", path, e),
+        )
+    })?;
+    let borders = parse_country_borders(&raw_csv)
+        .map_err(|message| reload_failed("country_borders", message))?;
+    if borders.is_empty() {
+        return Err(reload_failed(
+            "country_borders",
+            format!("'{}' does not contain any borders", path),
+        ));
+    }
+    Ok(borders)
+}
+
+pub async fn reload_from_sources(state: &AppState) -> Result<ReloadSummary, ErrorResponse> {
+    reload_with_borders(state, &country_borders_path()).await
+}
+
+pub async fn reload_with_borders(
+    state: &AppState,
+    borders_path: &str,
+) -> Result<ReloadSummary, ErrorResponse> {
+    let _order = state.order_store_writes().await;
+    let mut operators = read_operators(state).await?;
+    let backfilled_ids = state.assign_missing_ids(&mut operators);
+    validate_reloaded_operators(&operators, state.e164_validation_mode)
+        .map_err(|message| reload_failed("operator_mappings", message))?;
+    let borders = read_borders(borders_path).await?;
+
+    if backfilled_ids > 0 {
+        let store = Arc::clone(&state.store);
+        let snapshot = operators.clone();
+        task::spawn_blocking(move || store.snapshot(&snapshot))
+            .await
+            .map_err(|_| ErrorResponse::InternalError)?
+            .map_err(|_| ErrorResponse::InternalError)?;
+    }
+
+    let summary = ReloadSummary {
+        operators: operators.len(),
+        countries_with_borders: borders.len(),
+        backfilled_ids,
+    };
+    {
+        let mut operators_guard = state
+            .operators
+            .write()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        let mut borders_guard = state
+            .borders
+            .write()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        *operators_guard = operators.iter().cloned().map(Arc::new).collect();
+        state.rebuild_indexes(&operators_guard)?;
+        *borders_guard = borders;
+    }
+    state
+        .history
+        .reconcile(&operators)
+        .await
+        .map_err(|_| ErrorResponse::InternalError)?;
+
+    Ok(summary)
+}
+
+async fn modified_at(path: &str) -> Option<SystemTime> {
+    fs::metadata(path).await.and_then(|m| m.modified()).ok()
+}
+
+pub struct SourceWatch {
+    paths: Vec<String>,
+    last_seen: Vec<Option<SystemTime>>,
+}
+
+impl SourceWatch {
+    pub async fn new(paths: Vec<String>) -> Self {
+        let mut last_seen = Vec::with_capacity(paths.len());
+        for path in &paths {
+            last_seen.push(modified_at(path).await);
+        }
+        SourceWatch { paths, last_seen }
+    }
+
+    pub async fn changed(&mut self, state: &AppState) -> bool {
+        let own_write = state.store.snapshot_written_at();
+        let mut changed = false;
+        for (path, seen) in self.paths.iter().zip(self.last_seen.iter_mut()) {
+            let current = modified_at(path).await;
+            if current == *seen {
+                continue;
+            }
+            *seen = current;
+            let written_by_store =
+                *path == state.mappings_file_path && current.is_some() && current == own_write;
+            changed |= !written_by_store;
+        }
+        changed
+    }
+}
+
+pub fn spawn_reload_watcher(shared_state: Data<AppState>, interval_seconds: u64) {
+    if interval_seconds == 0 {
+        return;
+    }
+    task::spawn(async move {
+        let mut watch =
+            SourceWatch::new(vec![operator_mappings_path(), country_borders_path()]).await;
+        let mut ticker = time::interval(Duration::from_secs(interval_seconds));
+        loop {
+            ticker.tick().await;
+            if !watch.changed(&shared_state).await {
+                continue;
+            }
+            match reload_from_sources(&shared_state).await {
+                Ok(summary) => log::info!(
+                    "Reloaded {} operators and borders for {} countries after a file change",
+                    summary.operators,
+                    summary.countries_with_borders
+                ),
+                Err(e) => log::error!("Reload after a file change failed, keeping old data: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,91 @@
+use crate::utils::models::{Operator, SearchField};
+use std::{collections::HashMap, sync::Arc};
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenHit {
+    pub operator_index: usize,
+    pub field: SearchField,
+}
+
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    postings: HashMap<String, Vec<TokenHit>>,
+}
+
+pub fn search_tokens(value: &str) -> Vec<String> {
+    let folded: String = value
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    folded
+        .to_lowercase()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect()
+}
+
+pub fn edit_distance(a: &str, b: &str, limit: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > limit {
+        return None;
+    }
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        if current.iter().min().is_some_and(|&best| best > limit) {
+            return None;
+        }
+        previous = current;
+    }
+    Some(previous[b.len()]).filter(|&distance| distance <= limit)
+}
+
+impl SearchIndex {
+    fn add(&mut self, token: String, hit: TokenHit) {
+        let hits = self.postings.entry(token).or_default();
+        if !hits.contains(&hit) {
+            hits.push(hit);
+        }
+    }
+
+    fn add_text(&mut self, value: &str, hit: TokenHit) {
+        for token in search_tokens(value) {
+            self.add(token, hit);
+        }
+    }
+
+    pub fn build(operators: &[Arc<Operator>]) -> Self {
+        let mut index = SearchIndex::default();
+        for (operator_index, op) in operators.iter().enumerate() {
+            let hit = |field| TokenHit {
+                operator_index,
+                field,
+            };
+            for name in op.all_names() {
+                index.add_text(name, hit(SearchField::Name));
+            }
+            index.add_text(&op.country, hit(SearchField::Country));
+            for realm in op.realm.iter().flatten() {
+                index.add_text(realm, hit(SearchField::Realm));
+            }
+            for tadig in op.tadig.iter().flatten() {
+                index.add(tadig.to_lowercase(), hit(SearchField::Tadig));
+                index.add_text(tadig, hit(SearchField::Tadig));
+            }
+        }
+        index
+    }
+
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, &[TokenHit])> {
+        self.postings
+            .iter()
+            .map(|(token, hits)| (token.as_str(), hits.as_slice()))
+    }
+}
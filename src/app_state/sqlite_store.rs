@@ -0,0 +1,173 @@
+use crate::app_state::store::{OperatorStore, operator_not_stored};
+use crate::utils::models::Operator;
+use rusqlite::{Connection, OptionalExtension, params};
+use std::{io, sync::Mutex};
+
+pub struct SqliteStore {
+    connection: Mutex<Connection>,
+}
+
+fn sqlite_error(e: rusqlite::Error) -> io::Error {
+    io::Error::other(e)
+}
+
+fn to_body(operator: &Operator) -> io::Result<String> {
+    serde_json::to_string(operator).map_err(io::Error::other)
+}
+
+fn from_body(body: &str) -> io::Result<Operator> {
+    serde_json::from_str(body).map_err(io::Error::other)
+}
+
+impl SqliteStore {
+    pub fn open(database_path: &str) -> io::Result<Self> {
+        let connection = Connection::open(database_path).map_err(sqlite_error)?;
+        Self::initialise(connection)
+    }
+
+    pub fn in_memory() -> io::Result<Self> {
+        let connection = Connection::open_in_memory().map_err(sqlite_error)?;
+        Self::initialise(connection)
+    }
+
+    fn initialise(connection: Connection) -> io::Result<Self> {
+        connection
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS operators (
+                    id   INTEGER PRIMARY KEY,
+                    body TEXT NOT NULL
+                );",
+            )
+            .map_err(sqlite_error)?;
+        Ok(SqliteStore {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn with_connection<T>(
+        &self,
+        action: impl FnOnce(&mut Connection) -> io::Result<T>,
+    ) -> io::Result<T> {
+        let mut connection = self
+            .connection
+            .lock()
+            .map_err(|_| io::Error::other("Lock poisoned"))?;
+        action(&mut connection)
+    }
+}
+
+impl OperatorStore for SqliteStore {
+    fn load(&self) -> io::Result<Vec<Operator>> {
+        self.with_connection(|connection| {
+            let mut statement = connection
+                .prepare("SELECT body FROM operators ORDER BY id")
+                .map_err(sqlite_error)?;
+            let bodies = statement
+                .query_map([], |row| row.get::<_, String>(0))
+                .map_err(sqlite_error)?;
+            bodies
+                .map(|body| from_body(&body.map_err(sqlite_error)?))
+                .collect()
+        })
+    }
+
+    fn get(&self, id: u64) -> io::Result<Option<Operator>> {
+        self.with_connection(|connection| {
+            let body: Option<String> = connection
+                .query_row(
+                    "SELECT body FROM operators WHERE id = ?1",
+                    params![id as i64],
+                    |row| row.get(0),
+                )
+                .optional()
+                .map_err(sqlite_error)?;
+            body.as_deref().map(from_body).transpose()
+        })
+    }
+
+    fn insert(&self, operator: &Operator) -> io::Result<()> {
+        let body = to_body(operator)?;
+        self.with_connection(|connection| {
+            connection
+                .execute(
+                    "INSERT INTO operators (id, body) VALUES (?1, ?2)",
+                    params![operator.id as i64, body],
+                )
+                .map_err(sqlite_error)?;
+            Ok(())
+        })
+    }
+
+    fn insert_batch(&self, operators: &[Operator]) -> io::Result<()> {
+        let bodies = operators
+            .iter()
+            .map(|op| Ok((op.id, to_body(op)?)))
+            .collect::<io::Result<Vec<(u64, String)>>>()?;
+        self.with_connection(|connection| {
+            let transaction = connection.transaction().map_err(sqlite_error)?;
+            {
+                let mut statement = transaction
+                    .prepare("INSERT INTO operators (id, body) VALUES (?1, ?2)")
+                    .map_err(sqlite_error)?;
+                for (id, body) in &bodies {
+                    statement
+                        .execute(params![*id as i64, body])
+                        .map_err(sqlite_error)?;
+                }
+            }
+            transaction.commit().map_err(sqlite_error)
+        })
+    }
+
+    fn update(&self, operator: &Operator) -> io::Result<()> {
+        let body = to_body(operator)?;
+        self.with_connection(|connection| {
+            let changed = connection
+                .execute(
+                    "UPDATE operators SET body = ?2 WHERE id = ?1",
+                    params![operator.id as i64, body],
+                )
+                .map_err(sqlite_error)?;
+            if changed == 0 {
+                return Err(operator_not_stored(operator.id));
+            }
+            Ok(())
+        })
+    }
+
+    fn delete(&self, id: u64) -> io::Result<()> {
+        self.with_connection(|connection| {
+            let changed = connection
+                .execute("DELETE FROM operators WHERE id = ?1", params![id as i64])
+                .map_err(sqlite_error)?;
+            if changed == 0 {
+                return Err(operator_not_stored(id));
+            }
+            Ok(())
+        })
+    }
+
+    fn snapshot(&self, operators: &[Operator]) -> io::Result<()> {
+        let bodies = operators
+            .iter()
+            .map(|op| Ok((op.id, to_body(op)?)))
+            .collect::<io::Result<Vec<(u64, String)>>>()?;
+        self.with_connection(|connection| {
+            let transaction = connection.transaction().map_err(sqlite_error)?;
+            transaction
+                .execute("DELETE FROM operators", [])
+                .map_err(sqlite_error)?;
+            {
+                let mut statement = transaction
+                    .prepare("INSERT INTO operators (id, body) VALUES (?1, ?2)")
+                    .map_err(sqlite_error)?;
+                for (id, body) in &bodies {
+                    statement
+                        .execute(params![*id as i64, body])
+                        .map_err(sqlite_error)?;
+                }
+            }
+            transaction.commit().map_err(sqlite_error)
+        })
+    }
+}
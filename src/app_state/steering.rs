@@ -0,0 +1,4 @@
+use crate::app_state::json_registry::JsonRegistry;
+use crate::utils::models::SteeringPreference;
+
+pub type SteeringRegistry = JsonRegistry<SteeringPreference>;
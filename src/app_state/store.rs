@@ -0,0 +1,27 @@
+use crate::utils::models::Operator;
+use std::{io, time::SystemTime};
+
+pub trait OperatorStore: Send + Sync {
+    fn load(&self) -> io::Result<Vec<Operator>>;
+    fn get(&self, id: u64) -> io::Result<Option<Operator>>;
+    fn insert(&self, operator: &Operator) -> io::Result<()>;
+    fn insert_batch(&self, operators: &[Operator]) -> io::Result<()>;
+    fn update(&self, operator: &Operator) -> io::Result<()>;
+    fn delete(&self, id: u64) -> io::Result<()>;
+    fn snapshot(&self, operators: &[Operator]) -> io::Result<()>;
+
+    fn compact(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn snapshot_written_at(&self) -> Option<SystemTime> {
+        None
+    }
+}
+
+pub(crate) fn operator_not_stored(id: u64) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("operator {} is not in the store", id),
+    )
+}
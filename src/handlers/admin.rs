@@ -0,0 +1,22 @@
+use crate::{
+    app_state::{model::AppState, reload::reload_from_sources},
+    operators::integrity::get_integrity_report,
+    utils::error_responses::ErrorResponse,
+};
+use actix_web::{HttpResponse, Result, web};
+use serde_json::json;
+
+pub async fn handle_reload(app_state: web::Data<AppState>) -> Result<HttpResponse, ErrorResponse> {
+    let summary = reload_from_sources(&app_state).await?;
+    Ok(HttpResponse::Ok().json(json!({
+        "status":  "successfully_reloaded",
+        "summary": summary
+    })))
+}
+
+pub async fn handle_integrity(
+    app_state: web::Data<AppState>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let report = get_integrity_report(&app_state)?;
+    Ok(HttpResponse::Ok().json(report))
+}
@@ -0,0 +1,65 @@
+use crate::{
+    app_state::model::AppState,
+    operators::audit::query_audit_log,
+    utils::{
+        error_responses::ErrorResponse,
+        models::{AuditOperation, AuditQuery, Operator},
+    },
+};
+use actix_web::{HttpRequest, HttpResponse, Result, web};
+
+pub const ACTOR_HEADER: &str = "X-Actor";
+
+pub fn request_actor(req: &HttpRequest) -> String {
+    req.headers()
+        .get(ACTOR_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|actor| !actor.is_empty())
+        .unwrap_or("anonymous")
+        .to_string()
+}
+
+pub async fn record_mutation(
+    app_state: &AppState,
+    actor: &str,
+    operation: AuditOperation,
+    tadig: &str,
+    before: Option<&Operator>,
+    after: Option<&Operator>,
+) -> Result<(), ErrorResponse> {
+    let lineage = match before {
+        Some(previous) => app_state.history.live_lineage(previous.id)?,
+        None => None,
+    };
+    record_mutation_in_lineage(app_state, actor, operation, tadig, before, after, lineage).await
+}
+
+pub async fn record_mutation_in_lineage(
+    app_state: &AppState,
+    actor: &str,
+    operation: AuditOperation,
+    tadig: &str,
+    before: Option<&Operator>,
+    after: Option<&Operator>,
+    lineage: Option<u64>,
+) -> Result<(), ErrorResponse> {
+    app_state
+        .audit_log
+        .record(actor, operation, tadig, before, after)
+        .await
+        .map_err(|_| ErrorResponse::InternalError)?;
+    app_state
+        .history
+        .record(operation, lineage, after)
+        .await
+        .map_err(|_| ErrorResponse::InternalError)
+}
+
+pub async fn handle_get_audit_log(
+    app_state: web::Data<AppState>,
+    query: web::Query<AuditQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let entries = query_audit_log(&app_state, &query)?;
+    Ok(HttpResponse::Ok().json(entries))
+}
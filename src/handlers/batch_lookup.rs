@@ -0,0 +1,52 @@
+use crate::{
+    app_state::model::AppState,
+    operators::batch_lookup::lookup_operators_batch,
+    utils::{
+        error_responses::ErrorResponse,
+        models::{BatchLookupItemResponse, BatchLookupRequest, BatchLookupResponse},
+    },
+};
+use actix_web::{HttpResponse, Result, web};
+
+pub async fn handle_batch_lookup(
+    app_state: web::Data<AppState>,
+    payload: web::Json<BatchLookupRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let request = payload.into_inner();
+    let outcomes = lookup_operators_batch(&app_state, &request.items, request.match_mode)?;
+
+    let results: Vec<BatchLookupItemResponse> = outcomes
+        .iter()
+        .enumerate()
+        .map(|(index, outcome)| match outcome {
+            Ok(found) => BatchLookupItemResponse {
+                index,
+                operator: Some(&found.operator),
+                matched_prefix: found.matched_prefix.as_deref(),
+                matched_prefix_length: found.matched_prefix.as_ref().map(String::len),
+                status: None,
+                error: None,
+            },
+            Err(e) => {
+                let (status, body) = e.clone().into_status_and_body();
+                BatchLookupItemResponse {
+                    index,
+                    operator: None,
+                    matched_prefix: None,
+                    matched_prefix_length: None,
+                    status: Some(status.as_u16()),
+                    error: Some(body),
+                }
+            }
+        })
+        .collect();
+
+    let failed = results.iter().filter(|r| r.error.is_some()).count();
+    Ok(HttpResponse::Ok().json(BatchLookupResponse {
+        match_mode: request.match_mode,
+        total: results.len(),
+        matched: results.len() - failed,
+        failed,
+        results,
+    }))
+}
@@ -0,0 +1,102 @@
+use crate::{
+    app_state::{
+        model::AppState,
+        persistence::{persist_insert_batch, roll_back},
+    },
+    handlers::audit::{record_mutation, request_actor},
+    operators::crud_operations::bulk_import::import_operators,
+    utils::{
+        csv_format::operators_from_csv,
+        error_responses::ErrorResponse,
+        models::{AuditOperation, CreateOperator, ImportQuery, ImportReport, ImportRowReport},
+    },
+};
+use actix_web::{HttpRequest, HttpResponse, Result, web};
+
+pub async fn handle_bulk_import(
+    req: HttpRequest,
+    app_state: web::Data<AppState>,
+    query: web::Query<ImportQuery>,
+    rows: web::Json<Vec<CreateOperator>>,
+) -> Result<HttpResponse, ErrorResponse> {
+    import_and_report(&req, &app_state, &rows, query.dry_run).await
+}
+
+pub async fn handle_bulk_import_csv(
+    req: HttpRequest,
+    app_state: web::Data<AppState>,
+    query: web::Query<ImportQuery>,
+    raw_csv: String,
+) -> Result<HttpResponse, ErrorResponse> {
+    let rows = operators_from_csv(&raw_csv)?;
+    import_and_report(&req, &app_state, &rows, query.dry_run).await
+}
+
+async fn import_and_report(
+    req: &HttpRequest,
+    app_state: &AppState,
+    rows: &[CreateOperator],
+    dry_run: bool,
+) -> Result<HttpResponse, ErrorResponse> {
+    let _order = app_state.order_store_writes().await;
+    let outcome = import_operators(app_state, rows, dry_run)?;
+
+    if outcome.committed {
+        persist_insert_batch(app_state, &outcome.imported)
+            .await
+            .map_err(|e| roll_back(app_state, e, &outcome.imported, &[]))?;
+        let actor = request_actor(req);
+        for operator in &outcome.imported {
+            record_mutation(
+                app_state,
+                &actor,
+                AuditOperation::Import,
+                operator.primary_tadig(),
+                None,
+                Some(operator),
+            )
+            .await?;
+        }
+    }
+
+    let row_reports: Vec<ImportRowReport> = outcome
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(row, row_outcome)| match &row_outcome.result {
+            Ok(()) => ImportRowReport {
+                row,
+                tadig: &row_outcome.tadig,
+                status: None,
+                error: None,
+            },
+            Err(e) => {
+                let (status, body) = e.clone().into_status_and_body();
+                ImportRowReport {
+                    row,
+                    tadig: &row_outcome.tadig,
+                    status: Some(status.as_u16()),
+                    error: Some(body),
+                }
+            }
+        })
+        .collect();
+
+    let rejected = row_reports.iter().filter(|r| r.error.is_some()).count();
+    let report = ImportReport {
+        dry_run,
+        committed: outcome.committed,
+        total: row_reports.len(),
+        accepted: row_reports.len() - rejected,
+        rejected,
+        rows: row_reports,
+    };
+
+    if rejected > 0 {
+        Ok(HttpResponse::UnprocessableEntity().json(report))
+    } else if outcome.committed {
+        Ok(HttpResponse::Created().json(report))
+    } else {
+        Ok(HttpResponse::Ok().json(report))
+    }
+}
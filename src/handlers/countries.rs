@@ -0,0 +1,6 @@
+use crate::utils::{countries::COUNTRIES, error_responses::ErrorResponse};
+use actix_web::{HttpResponse, Result};
+
+pub async fn handle_list_countries() -> Result<HttpResponse, ErrorResponse> {
+    Ok(HttpResponse::Ok().json(&*COUNTRIES))
+}
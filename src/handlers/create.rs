@@ -1,19 +1,32 @@
 use crate::app_state::AppState;
-use crate::app_state::persistence::save_to_disk;
+use crate::app_state::persistence::{persist_insert, roll_back};
+use crate::handlers::audit::{record_mutation, request_actor};
 use crate::operators::crud_operations::create::create_operator;
 use crate::utils::error_responses::ErrorResponse;
-use crate::utils::models::CreateOperator;
-use actix_web::{HttpResponse, web};
+use crate::utils::models::{AuditOperation, CreateOperator};
+use actix_web::{HttpRequest, HttpResponse, web};
 use serde_json::json;
+use std::slice;
 
 pub async fn handle_create_operator(
+    req: HttpRequest,
     app_data: web::Data<AppState>,
     new_op: web::Json<CreateOperator>,
 ) -> Result<HttpResponse, ErrorResponse> {
+    let _order = app_data.order_store_writes().await;
     let operator = create_operator(&app_data, new_op.into_inner())?;
-    save_to_disk(&app_data, &app_data.mappings_file_path)
+    persist_insert(&app_data, &operator)
         .await
-        .map_err(|_| ErrorResponse::InternalError)?;
+        .map_err(|e| roll_back(&app_data, e, slice::from_ref(&operator), &[]))?;
+    record_mutation(
+        &app_data,
+        &request_actor(&req),
+        AuditOperation::Create,
+        operator.primary_tadig(),
+        None,
+        Some(&operator),
+    )
+    .await?;
 
     Ok(HttpResponse::Created().json(json!({
         "status":   "successfully created",
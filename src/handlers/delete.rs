@@ -1,21 +1,58 @@
 use crate::{
-    app_state::{model::AppState, persistence::save_to_disk},
+    app_state::{
+        model::AppState,
+        persistence::{persist_delete, roll_back},
+    },
+    handlers::{
+        audit::{record_mutation, request_actor},
+        update::if_match_header,
+    },
     operators::crud_operations::delete::delete_operator,
-    utils::error_responses::ErrorResponse,
+    utils::{
+        error_responses::ErrorResponse,
+        models::{AuditOperation, OperatorKey},
+    },
 };
-use actix_web::{HttpResponse, web};
+use actix_web::{HttpRequest, HttpResponse, web};
+use std::slice;
+
+async fn delete_by_key(
+    req: &HttpRequest,
+    app_state: &AppState,
+    key: OperatorKey<'_>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let _order = app_state.order_store_writes().await;
+    let removed_operator = delete_operator(app_state, key, if_match_header(req))?;
+
+    persist_delete(app_state, removed_operator.id)
+        .await
+        .map_err(|e| roll_back(app_state, e, &[], slice::from_ref(&removed_operator)))?;
+    record_mutation(
+        app_state,
+        &request_actor(req),
+        AuditOperation::Delete,
+        key.tadig_for(&removed_operator),
+        Some(&removed_operator),
+        None,
+    )
+    .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
 
 pub async fn handle_delete_operator(
+    req: HttpRequest,
     app_state: web::Data<AppState>,
     path: web::Path<String>,
 ) -> Result<HttpResponse, ErrorResponse> {
     let tadig = path.into_inner();
+    delete_by_key(&req, &app_state, OperatorKey::Tadig(&tadig)).await
+}
 
-    delete_operator(&app_state, &tadig)?;
-
-    save_to_disk(&app_state, &app_state.mappings_file_path)
-        .await
-        .map_err(|_| ErrorResponse::InternalError)?;
-
-    Ok(HttpResponse::NoContent().finish())
+pub async fn handle_delete_operator_by_id(
+    req: HttpRequest,
+    app_state: web::Data<AppState>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ErrorResponse> {
+    delete_by_key(&req, &app_state, OperatorKey::Id(path.into_inner())).await
 }
@@ -18,5 +18,9 @@ pub async fn handle_find_roaming_partners(
             expected: "a non-empty TADIG".to_string(),
         });
     }
-    Ok(HttpResponse::Ok().json(find_roaming_partners(&app_state, tadig)?))
+    let max_hops = query.max_hops.unwrap_or(1);
+    let contract = query.contract.unwrap_or_default();
+    Ok(HttpResponse::Ok().json(find_roaming_partners(
+        &app_state, tadig, max_hops, contract,
+    )?))
 }
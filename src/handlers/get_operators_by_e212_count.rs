@@ -1,12 +1,14 @@
 use crate::{
     app_state::model::AppState,
-    operators::get_operators_by_e212_count::get_operators_grouped_by_e212_count,
-    utils::error_responses::ErrorResponse,
+    operators::get_operators_by_e212_count::get_operators_grouped_by_size,
+    utils::{config, error_responses::ErrorResponse, models::SizeQuery},
 };
 use actix_web::{HttpResponse, Result, web};
 
 pub async fn handle_get_operators_by_e212_count(
     app_state: web::Data<AppState>,
+    query: web::Query<SizeQuery>,
 ) -> Result<HttpResponse, ErrorResponse> {
-    Ok(HttpResponse::Ok().json(get_operators_grouped_by_e212_count(&app_state)?))
+    let cfg = config::load().map_err(|_| ErrorResponse::InternalError)?;
+    Ok(HttpResponse::Ok().json(get_operators_grouped_by_size(&app_state, &cfg, &query)?))
 }
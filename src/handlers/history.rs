@@ -0,0 +1,97 @@
+use crate::{
+    app_state::{
+        model::AppState,
+        persistence::{persist_insert, persist_update, roll_back},
+    },
+    handlers::audit::{record_mutation_in_lineage, request_actor},
+    operators::history::{get_operator_history, restore_operator_version},
+    utils::{
+        error_responses::ErrorResponse,
+        models::{AuditOperation, OperatorKey, RestoreQuery},
+    },
+};
+use actix_web::{HttpRequest, HttpResponse, Result, web};
+use serde_json::json;
+use std::slice;
+
+async fn restore_by_key(
+    req: &HttpRequest,
+    app_state: &AppState,
+    key: OperatorKey<'_>,
+    version: u32,
+) -> Result<HttpResponse, ErrorResponse> {
+    let _order = app_state.order_store_writes().await;
+    let restored = restore_operator_version(app_state, key, version)?;
+
+    match &restored.previous {
+        Some(previous) => persist_update(app_state, &restored.restored)
+            .await
+            .map_err(|e| roll_back(app_state, e, &[], slice::from_ref(previous)))?,
+        None => persist_insert(app_state, &restored.restored)
+            .await
+            .map_err(|e| roll_back(app_state, e, slice::from_ref(&restored.restored), &[]))?,
+    }
+    record_mutation_in_lineage(
+        app_state,
+        &request_actor(req),
+        AuditOperation::Restore,
+        key.tadig_for(&restored.restored),
+        restored.previous.as_deref(),
+        Some(&restored.restored),
+        Some(restored.lineage),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status":           "successfully_restored",
+        "restored_version": version,
+        "operator":         *restored.restored
+    })))
+}
+
+pub async fn handle_get_operator_history(
+    app_state: web::Data<AppState>,
+    tadig_path: web::Path<String>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let versions = get_operator_history(&app_state, tadig_path.as_str())?;
+    Ok(HttpResponse::Ok().json(versions))
+}
+
+pub async fn handle_get_operator_history_by_id(
+    app_state: web::Data<AppState>,
+    id_path: web::Path<u64>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let versions = get_operator_history(&app_state, id_path.into_inner())?;
+    Ok(HttpResponse::Ok().json(versions))
+}
+
+pub async fn handle_restore_operator(
+    req: HttpRequest,
+    app_state: web::Data<AppState>,
+    tadig_path: web::Path<String>,
+    query: web::Query<RestoreQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let tadig_key = tadig_path.into_inner();
+    restore_by_key(
+        &req,
+        &app_state,
+        OperatorKey::Tadig(&tadig_key),
+        query.version,
+    )
+    .await
+}
+
+pub async fn handle_restore_operator_by_id(
+    req: HttpRequest,
+    app_state: web::Data<AppState>,
+    id_path: web::Path<u64>,
+    query: web::Query<RestoreQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    restore_by_key(
+        &req,
+        &app_state,
+        OperatorKey::Id(id_path.into_inner()),
+        query.version,
+    )
+    .await
+}
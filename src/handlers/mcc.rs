@@ -0,0 +1,11 @@
+use crate::{
+    app_state::model::AppState, operators::mcc::list_mncs, utils::error_responses::ErrorResponse,
+};
+use actix_web::{HttpResponse, Result, web};
+
+pub async fn handle_list_mncs(
+    app_state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ErrorResponse> {
+    Ok(HttpResponse::Ok().json(list_mncs(&app_state, &path)?))
+}
@@ -1,9 +1,19 @@
+pub mod admin;
+pub mod audit;
+pub mod batch_lookup;
+pub mod bulk_import;
+pub mod countries;
 pub mod create;
 pub mod delete;
 pub mod find_roaming_partners;
 pub mod get_operators_by_e212_count;
 pub mod group_operators_by_iso3;
+pub mod history;
+pub mod mcc;
 pub mod network_names;
 pub mod read;
+pub mod roaming_agreements;
+pub mod search;
+pub mod steering;
+pub mod swagger;
 pub mod update;
-pub mod swagger;
\ No newline at end of file
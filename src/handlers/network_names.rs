@@ -1,13 +1,24 @@
 use actix_web::{HttpResponse, Result, web};
 
 use crate::{
-    app_state::model::AppState, operators::network_names::retrieve_network_names,
-    utils::error_responses::ErrorResponse,
+    app_state::model::AppState,
+    operators::network_names::{
+        parse_name_kind, retrieve_network_names, retrieve_network_names_by_type,
+    },
+    utils::{error_responses::ErrorResponse, models::NetworkNamesQuery},
 };
 
 pub async fn handle_network_names(
     app_state: web::Data<AppState>,
+    query: web::Query<NetworkNamesQuery>,
 ) -> Result<HttpResponse, ErrorResponse> {
-    let network_names = retrieve_network_names(&app_state)?;
+    if query.by_type {
+        return Ok(HttpResponse::Ok().json(retrieve_network_names_by_type(&app_state)?));
+    }
+    let kind = match query.kind.as_deref() {
+        Some(label) => parse_name_kind(label)?,
+        None => None,
+    };
+    let network_names = retrieve_network_names(&app_state, kind)?;
     Ok(HttpResponse::Ok().json(network_names))
 }
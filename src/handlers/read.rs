@@ -1,24 +1,78 @@
 use crate::app_state::AppState;
-use crate::operators::crud_operations::read::lookup_operator_by_query;
+use crate::operators::crud_operations::{
+    list::{ProjectedOperator, list_operators, page_links, parse_fields},
+    read::{find_operator_by_id, lookup_operator_by_query},
+};
+use crate::utils::csv_format::operators_to_csv;
 use crate::utils::error_responses::ErrorResponse;
-use crate::utils::models::{Operator, QueryType, ReadQuery};
-use actix_web::http::header::ContentType;
-use actix_web::{HttpResponse, web};
+use crate::utils::models::{
+    Operator, OperatorLookupResponse, QueryType, ReadQuery, ResponseFormat,
+};
+use actix_web::http::header::{self, ContentType};
+use actix_web::{HttpRequest, HttpResponse, web};
 use serde_json;
-use std::sync::Arc;
+
+fn accepts_csv(req: &HttpRequest) -> bool {
+    req.headers()
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("text/csv"))
+}
 
 pub async fn handle_get_operator(
+    req: HttpRequest,
     app_data: web::Data<AppState>,
     query: web::Query<ReadQuery>,
 ) -> Result<HttpResponse, ErrorResponse> {
-    let operators = app_data
-        .operators
-        .read()
-        .map_err(|_| ErrorResponse::InternalError)?;
-
     if query.imsi.is_none() && query.msisdn.is_none() && query.tadig.is_none() {
-        let all_ops: Vec<&Operator> = operators.iter().map(|op| op.as_ref()).collect();
-        return Ok(HttpResponse::Ok().json(all_ops));
+        let operators = app_data
+            .operators
+            .read()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        let historical_ops = match query.as_of {
+            Some(as_of) => Some(app_data.history.snapshot_as_of(as_of)?),
+            None => None,
+        };
+        let all_ops: Vec<&Operator> = match &historical_ops {
+            Some(snapshot) => snapshot.iter().collect(),
+            None => operators.iter().map(|op| op.as_ref()).collect(),
+        };
+        let fields = query.fields.as_deref().map(parse_fields).transpose()?;
+        let listing = list_operators(&all_ops, &query)?;
+
+        let mut response = HttpResponse::Ok();
+        response.insert_header(("X-Total-Count", listing.total.to_string()));
+        let links = page_links(req.path(), req.query_string(), listing.page, listing.total);
+        if !links.is_empty() {
+            response.insert_header((header::LINK, links.join(", ")));
+        }
+
+        let wants_csv = match query.format {
+            Some(format) => format == ResponseFormat::Csv,
+            None => accepts_csv(&req),
+        };
+        if wants_csv {
+            let body = operators_to_csv(&listing.operators)?;
+            return Ok(response
+                .content_type("text/csv; charset=utf-8")
+                .insert_header((
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=\"operators.csv\"",
+                ))
+                .body(body));
+        }
+        let Some(fields) = fields else {
+            return Ok(response.json(listing.operators));
+        };
+        let projected: Vec<ProjectedOperator> = listing
+            .operators
+            .iter()
+            .map(|operator| ProjectedOperator {
+                operator,
+                fields: &fields,
+            })
+            .collect();
+        return Ok(response.json(projected));
     }
 
     let (search_str, search_type) = if let Some(ref imsi) = query.imsi {
@@ -29,16 +83,33 @@ pub async fn handle_get_operator(
         (query.tadig.as_ref().unwrap().as_str(), QueryType::Tadig)
     };
 
-    let maybe_op = lookup_operator_by_query(&app_data, search_str, search_type)?;
+    let maybe_op = lookup_operator_by_query(&app_data, search_str, search_type, query.match_mode)?;
 
     match maybe_op {
         None => Ok(HttpResponse::NotFound().finish()),
-        Some(op_arc) => {
-            let body = serde_json::to_string_pretty(Arc::as_ref(&op_arc))
+        Some(found) => {
+            let response = OperatorLookupResponse {
+                operator: &found.operator,
+                match_mode: found.matched_prefix.as_ref().map(|_| query.match_mode),
+                matched_prefix: found.matched_prefix.as_deref(),
+                matched_prefix_length: found.matched_prefix.as_ref().map(String::len),
+            };
+            let body = serde_json::to_string_pretty(&response)
                 .map_err(|_| ErrorResponse::InternalError)?;
             Ok(HttpResponse::Ok()
                 .content_type(ContentType::json())
+                .insert_header((header::ETAG, found.operator.etag()))
                 .body(body))
         }
     }
 }
+
+pub async fn handle_get_operator_by_id(
+    app_data: web::Data<AppState>,
+    id_path: web::Path<u64>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let operator = find_operator_by_id(&app_data, id_path.into_inner())?;
+    Ok(HttpResponse::Ok()
+        .insert_header((header::ETAG, operator.etag()))
+        .json(&*operator))
+}
@@ -0,0 +1,57 @@
+use crate::{
+    app_state::model::AppState,
+    operators::roaming_agreements::{
+        create_agreement, delete_agreement, get_agreement, list_agreements, replace_agreement,
+    },
+    utils::{
+        error_responses::ErrorResponse,
+        models::{AgreementQuery, CreateRoamingAgreement},
+    },
+};
+use actix_web::{HttpResponse, Result, web};
+use serde_json::json;
+
+pub async fn handle_list_agreements(
+    app_state: web::Data<AppState>,
+    query: web::Query<AgreementQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    Ok(HttpResponse::Ok().json(list_agreements(&app_state, &query)?))
+}
+
+pub async fn handle_get_agreement(
+    app_state: web::Data<AppState>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ErrorResponse> {
+    Ok(HttpResponse::Ok().json(get_agreement(&app_state, path.into_inner())?))
+}
+
+pub async fn handle_create_agreement(
+    app_state: web::Data<AppState>,
+    body: web::Json<CreateRoamingAgreement>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let agreement = create_agreement(&app_state, body.into_inner()).await?;
+    Ok(HttpResponse::Created().json(json!({
+        "status":    "successfully created",
+        "agreement": agreement
+    })))
+}
+
+pub async fn handle_replace_agreement(
+    app_state: web::Data<AppState>,
+    path: web::Path<u64>,
+    body: web::Json<CreateRoamingAgreement>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let agreement = replace_agreement(&app_state, path.into_inner(), body.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(json!({
+        "status":    "successfully_updated",
+        "agreement": agreement
+    })))
+}
+
+pub async fn handle_delete_agreement(
+    app_state: web::Data<AppState>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ErrorResponse> {
+    delete_agreement(&app_state, path.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,14 @@
+use crate::{
+    app_state::model::AppState,
+    operators::search::search_operators,
+    utils::{error_responses::ErrorResponse, models::SearchQuery},
+};
+use actix_web::{HttpResponse, Result, web};
+
+pub async fn handle_search_operators(
+    app_state: web::Data<AppState>,
+    query: web::Query<SearchQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let text = query.q.as_deref().unwrap_or_default();
+    Ok(HttpResponse::Ok().json(search_operators(&app_state, text, query.limit)?))
+}
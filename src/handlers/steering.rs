@@ -0,0 +1,60 @@
+use crate::{
+    app_state::model::AppState,
+    operators::steering::{
+        delete_steering_preference, get_steering_preference, list_steering_preferences,
+        set_steering_preference, steer_subscriber,
+    },
+    utils::{
+        error_responses::ErrorResponse,
+        models::{SetSteeringPreference, SteeringQuery},
+        validations::validate_non_empty,
+    },
+};
+use actix_web::{HttpResponse, Result, web};
+use serde_json::json;
+
+pub async fn handle_steer(
+    app_state: web::Data<AppState>,
+    query: web::Query<SteeringQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let home = validate_non_empty("home", query.home.as_deref().unwrap_or_default())?;
+    let imsi = validate_non_empty("imsi", query.imsi.as_deref().unwrap_or_default())?;
+    Ok(HttpResponse::Ok().json(steer_subscriber(&app_state, home, imsi)?))
+}
+
+pub async fn handle_list_steering_preferences(
+    app_state: web::Data<AppState>,
+    query: web::Query<SteeringQuery>,
+) -> Result<HttpResponse, ErrorResponse> {
+    Ok(HttpResponse::Ok().json(list_steering_preferences(&app_state, &query)?))
+}
+
+pub async fn handle_get_steering_preference(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let (home, iso3) = path.into_inner();
+    Ok(HttpResponse::Ok().json(get_steering_preference(&app_state, &home, &iso3)?))
+}
+
+pub async fn handle_set_steering_preference(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+    body: web::Json<SetSteeringPreference>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let (home, iso3) = path.into_inner();
+    let preference = set_steering_preference(&app_state, &home, &iso3, body.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(json!({
+        "status":     "successfully_updated",
+        "preference": preference
+    })))
+}
+
+pub async fn handle_delete_steering_preference(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let (home, iso3) = path.into_inner();
+    delete_steering_preference(&app_state, &home, &iso3).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -1,49 +1,146 @@
-use actix_web::{HttpResponse, Result, web};
+use actix_web::{HttpRequest, HttpResponse, Result, http::header, web};
 use serde_json::json;
+use std::slice;
 
 use crate::{
-    app_state::{model::AppState, persistence::save_to_disk},
+    app_state::{
+        model::AppState,
+        persistence::{persist_update, roll_back},
+    },
+    handlers::audit::{record_mutation, request_actor},
     operators::crud_operations::update::{update_operator_by_patch, update_operator_by_put},
     utils::{
         error_responses::ErrorResponse,
-        models::{CreateOperator, PatchOperator},
+        models::{AuditOperation, CreateOperator, OperatorKey, OperatorUpdate, PatchOperator},
     },
 };
 
+pub fn if_match_header(req: &HttpRequest) -> Option<&str> {
+    req.headers()
+        .get(header::IF_MATCH)
+        .map(|value| value.to_str().unwrap_or_default())
+}
+
+async fn respond_with_update(
+    req: &HttpRequest,
+    app_state: &AppState,
+    operation: AuditOperation,
+    key: OperatorKey<'_>,
+    update: OperatorUpdate,
+) -> Result<HttpResponse, ErrorResponse> {
+    persist_update(app_state, &update.updated)
+        .await
+        .map_err(|e| roll_back(app_state, e, &[], slice::from_ref(&update.previous)))?;
+    record_mutation(
+        app_state,
+        &request_actor(req),
+        operation,
+        key.tadig_for(&update.previous),
+        Some(&update.previous),
+        Some(&update.updated),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header((header::ETAG, update.updated.etag()))
+        .json(json!({
+            "status":   "successfully_updated",
+            "operator": *update.updated
+        })))
+}
+
 pub async fn handle_update_by_put(
+    req: HttpRequest,
     app_state: web::Data<AppState>,
     tadig_path: web::Path<String>,
     operator_payload: web::Json<CreateOperator>,
 ) -> Result<HttpResponse, ErrorResponse> {
     let tadig_key = tadig_path.into_inner();
-    let updated_operator =
-        update_operator_by_put(&app_state, &tadig_key, operator_payload.into_inner())?;
-
-    save_to_disk(&app_state, &app_state.mappings_file_path)
-        .await
-        .map_err(|_| ErrorResponse::InternalError)?;
+    let _order = app_state.order_store_writes().await;
+    let update = update_operator_by_put(
+        &app_state,
+        &tadig_key,
+        operator_payload.into_inner(),
+        if_match_header(&req),
+    )?;
+    respond_with_update(
+        &req,
+        &app_state,
+        AuditOperation::Put,
+        OperatorKey::Tadig(&tadig_key),
+        update,
+    )
+    .await
+}
 
-    Ok(HttpResponse::Ok().json(json!({
-        "status":   "successfully_updated",
-        "operator": *updated_operator
-    })))
+pub async fn handle_update_by_put_id(
+    req: HttpRequest,
+    app_state: web::Data<AppState>,
+    id_path: web::Path<u64>,
+    operator_payload: web::Json<CreateOperator>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let id = id_path.into_inner();
+    let _order = app_state.order_store_writes().await;
+    let update = update_operator_by_put(
+        &app_state,
+        id,
+        operator_payload.into_inner(),
+        if_match_header(&req),
+    )?;
+    respond_with_update(
+        &req,
+        &app_state,
+        AuditOperation::Put,
+        OperatorKey::Id(id),
+        update,
+    )
+    .await
 }
 
 pub async fn handle_update_by_patch(
+    req: HttpRequest,
     app_state: web::Data<AppState>,
     tadig_path: web::Path<String>,
     patch_payload: web::Json<PatchOperator>,
 ) -> Result<HttpResponse, ErrorResponse> {
     let tadig_key = tadig_path.into_inner();
-    let updated_operator =
-        update_operator_by_patch(&app_state, &tadig_key, patch_payload.into_inner())?;
-
-    save_to_disk(&app_state, &app_state.mappings_file_path)
-        .await
-        .map_err(|_| ErrorResponse::InternalError)?;
+    let _order = app_state.order_store_writes().await;
+    let update = update_operator_by_patch(
+        &app_state,
+        &tadig_key,
+        patch_payload.into_inner(),
+        if_match_header(&req),
+    )?;
+    respond_with_update(
+        &req,
+        &app_state,
+        AuditOperation::Patch,
+        OperatorKey::Tadig(&tadig_key),
+        update,
+    )
+    .await
+}
 
-    Ok(HttpResponse::Ok().json(json!({
-        "status":   "successfully_updated",
-        "operator": *updated_operator
-    })))
+pub async fn handle_update_by_patch_id(
+    req: HttpRequest,
+    app_state: web::Data<AppState>,
+    id_path: web::Path<u64>,
+    patch_payload: web::Json<PatchOperator>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let id = id_path.into_inner();
+    let _order = app_state.order_store_writes().await;
+    let update = update_operator_by_patch(
+        &app_state,
+        id,
+        patch_payload.into_inner(),
+        if_match_header(&req),
+    )?;
+    respond_with_update(
+        &req,
+        &app_state,
+        AuditOperation::Patch,
+        OperatorKey::Id(id),
+        update,
+    )
+    .await
 }
@@ -1,14 +1,21 @@
 use actix_cors::Cors;
 use actix_web::{App, HttpResponse, HttpServer, dev::ServerHandle, web};
 use dotenv::dotenv;
-use std::io;
+use std::{io, sync::RwLock};
 
 use operator_mappings_api::{
     app_state::{
-        load_operator_mappings, loaders::load_country_borders, persistence::spawn_persistence_tasks,
+        load_operator_mappings,
+        loaders::{
+            load_audit_log, load_country_borders, load_operator_history, load_roaming_agreements,
+            load_steering_preferences,
+        },
+        persistence::spawn_persistence_tasks,
+        reload::spawn_reload_watcher,
     },
     handlers::swagger::configure_swagger,
-    utils::{config::load, routes::configure_routes},
+    operators::integrity::{check_dataset_integrity, enforce_integrity},
+    utils::{config::load, models::Operator, routes::configure_routes},
 };
 
 #[actix_web::main]
@@ -16,19 +23,41 @@ async fn main() -> io::Result<()> {
     dotenv().ok();
     env_logger::init();
 
-    let mut app_state = load_operator_mappings()
+    let mut app_state = load_operator_mappings().await.map_err(io::Error::other)?;
+    let borders = load_country_borders().await.map_err(io::Error::other)?;
+    app_state.borders = RwLock::new(borders);
+    app_state.audit_log = load_audit_log().await.map_err(io::Error::other)?;
+    app_state.history = load_operator_history().await.map_err(io::Error::other)?;
+    app_state.agreements = load_roaming_agreements().await.map_err(io::Error::other)?;
+    app_state.steering = load_steering_preferences()
         .await
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-    let borders = load_country_borders()
-        .await
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-    app_state.borders = borders;
+        .map_err(io::Error::other)?;
+    let loaded_operators: Vec<Operator> = app_state
+        .operators
+        .read()
+        .map_err(|_| io::Error::other("Lock poisoned"))?
+        .iter()
+        .map(|arc_op| (**arc_op).clone())
+        .collect();
+    let cfg = load().map_err(io::Error::other)?;
+    app_state.e164_validation_mode = cfg.e164_validation_mode;
+    enforce_integrity(
+        &check_dataset_integrity(&loaded_operators),
+        cfg.integrity_mode,
+        cfg.e164_validation_mode,
+    )
+    .map_err(io::Error::other)?;
+    app_state.history.reconcile(&loaded_operators).await?;
+    let highest_recorded_id = app_state
+        .history
+        .max_operator_id()
+        .map_err(|_| io::Error::other("Lock poisoned"))?;
+    app_state.reserve_operator_ids_through(highest_recorded_id);
 
     let shared_state = web::Data::new(app_state);
     let server_state = shared_state.clone();
     let spawn_state = shared_state.clone();
 
-    let cfg = load();
     let bind_addr = format!("{}:{}", cfg.host, cfg.port);
     println!("Server running at http://{}", bind_addr);
 
@@ -58,6 +87,7 @@ async fn main() -> io::Result<()> {
     .run();
 
     let handle: ServerHandle = server.handle();
+    spawn_reload_watcher(spawn_state.clone(), cfg.reload_watch_interval_seconds);
     spawn_persistence_tasks(spawn_state, cfg.save_interval_minutes, handle);
 
     server.await
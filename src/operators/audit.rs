@@ -0,0 +1,48 @@
+use crate::{
+    app_state::model::AppState,
+    utils::{
+        error_responses::ErrorResponse,
+        models::{AuditEntry, AuditQuery, OperatorValidationError},
+    },
+};
+
+fn entry_touches_tadig(entry: &AuditEntry, tadig: &str) -> bool {
+    entry.tadig.eq_ignore_ascii_case(tadig)
+        || entry.before.as_ref().is_some_and(|op| op.has_tadig(tadig))
+        || entry.after.as_ref().is_some_and(|op| op.has_tadig(tadig))
+}
+
+pub fn query_audit_log(
+    state: &AppState,
+    query: &AuditQuery,
+) -> Result<Vec<AuditEntry>, ErrorResponse> {
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        return Err(OperatorValidationError::FieldValidationError {
+            field: "from".to_string(),
+            message: "from must not be later than to".to_string(),
+            received: Some(from.to_rfc3339()),
+        }
+        .into());
+    }
+
+    let entries = state
+        .audit_log
+        .entries
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+
+    Ok(entries
+        .iter()
+        .filter(|entry| {
+            query
+                .tadig
+                .as_deref()
+                .is_none_or(|tadig| entry_touches_tadig(entry, tadig.trim()))
+        })
+        .filter(|entry| query.from.is_none_or(|from| entry.timestamp >= from))
+        .filter(|entry| query.to.is_none_or(|to| entry.timestamp <= to))
+        .cloned()
+        .collect())
+}
@@ -0,0 +1,76 @@
+use crate::{
+    app_state::{model::AppState, prefix_index::PrefixIndex},
+    operators::crud_operations::read::lookup_operator_in,
+    utils::{
+        error_responses::ErrorResponse,
+        models::{
+            BatchLookupItem, MatchMode, Operator, OperatorLookup, OperatorValidationError,
+            QueryType,
+        },
+    },
+};
+use std::sync::Arc;
+
+fn identify_batch_item(item: &BatchLookupItem) -> Result<(&str, QueryType, &str), ErrorResponse> {
+    match (&item.imsi, &item.msisdn, &item.tadig) {
+        (Some(imsi), None, None) => Ok((imsi, QueryType::Imsi, "imsi")),
+        (None, Some(msisdn), None) => Ok((msisdn, QueryType::Msisdn, "msisdn")),
+        (None, None, Some(tadig)) => Ok((tadig, QueryType::Tadig, "tadig")),
+        _ => Err(OperatorValidationError::FieldValidationError {
+            field: "item".to_string(),
+            message: "Exactly one of imsi, msisdn or tadig is required".to_string(),
+            received: None,
+        }
+        .into()),
+    }
+}
+
+fn resolve_batch_item(
+    operator_list: &[Arc<Operator>],
+    prefix_index: &PrefixIndex,
+    item: &BatchLookupItem,
+    match_mode: MatchMode,
+) -> Result<OperatorLookup, ErrorResponse> {
+    let (query_text, query_type, field) = identify_batch_item(item)?;
+    lookup_operator_in(
+        operator_list,
+        prefix_index,
+        query_text,
+        query_type,
+        match_mode,
+    )?
+    .ok_or_else(|| ErrorResponse::NotFound {
+        field: field.to_string(),
+        received: query_text.to_string(),
+        expected: format!("an operator matching the {}", field.to_uppercase()),
+    })
+}
+
+pub fn lookup_operators_batch(
+    state: &AppState,
+    items: &[BatchLookupItem],
+    match_mode: MatchMode,
+) -> Result<Vec<Result<OperatorLookup, ErrorResponse>>, ErrorResponse> {
+    if items.is_empty() {
+        return Err(OperatorValidationError::FieldValidationError {
+            field: "items".to_string(),
+            message: "items cannot be empty".to_string(),
+            received: None,
+        }
+        .into());
+    }
+
+    let guard = state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    let index = state
+        .prefix_index
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+
+    Ok(items
+        .iter()
+        .map(|item| resolve_batch_item(&guard, &index, item, match_mode))
+        .collect())
+}
@@ -0,0 +1,96 @@
+use crate::{
+    app_state::model::AppState,
+    operators::crud_operations::create::validate_create_fields,
+    utils::{
+        countries::resolve_country,
+        error_responses::ErrorResponse,
+        models::{
+            CreateOperator, ImportOutcome, ImportRowOutcome, Operator, OperatorDuplicateChecker,
+        },
+        validations::{validate_msisdn_country_codes, validate_numbering_plan},
+    },
+};
+use std::sync::Arc;
+
+fn resolve_import_row(row: &CreateOperator) -> Result<Operator, ErrorResponse> {
+    let country = resolve_country("country", &row.country)?;
+    Ok(Operator {
+        id: 0,
+        country: country.name.clone(),
+        e164: row.e164.clone(),
+        e212: row.e212.clone(),
+        iso2: country.alpha2.clone(),
+        iso3: country.alpha3.clone(),
+        name: row.name.clone(),
+        names: row.names.clone(),
+        realm: row.realm.clone(),
+        tadig: Some(row.tadig.clone().unwrap_or_default()),
+    })
+}
+
+pub fn import_operators(
+    state: &AppState,
+    rows: &[CreateOperator],
+    dry_run: bool,
+) -> Result<ImportOutcome, ErrorResponse> {
+    let mut guard_write = state
+        .operators
+        .write()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    let existing: Vec<Operator> = guard_write.iter().map(|arc| (**arc).clone()).collect();
+
+    let candidates: Vec<Result<Operator, ErrorResponse>> =
+        rows.iter().map(resolve_import_row).collect();
+
+    let mut duplicate_checker = OperatorDuplicateChecker::from_operators(&existing);
+    let mut row_outcomes = Vec::with_capacity(rows.len());
+    for (row, candidate) in rows.iter().zip(candidates.iter()) {
+        let result = match candidate {
+            Ok(new_op) => validate_create_fields(&duplicate_checker, row)
+                .and_then(|_| {
+                    validate_numbering_plan(
+                        new_op.e212.as_deref(),
+                        new_op.realm.as_deref(),
+                        &new_op.iso2,
+                    )
+                })
+                .and_then(|_| {
+                    validate_msisdn_country_codes(
+                        new_op.e164.as_deref(),
+                        &new_op.iso2,
+                        state.e164_validation_mode,
+                    )
+                })
+                .map(|_| {
+                    duplicate_checker.include(new_op);
+                }),
+            Err(e) => Err(e.clone()),
+        };
+        row_outcomes.push(ImportRowOutcome {
+            tadig: row.tadig.clone().unwrap_or_default(),
+            result,
+        });
+    }
+
+    let all_valid = row_outcomes.iter().all(|outcome| outcome.result.is_ok());
+    let committed = all_valid && !dry_run;
+    let mut imported = Vec::new();
+    if committed {
+        imported = candidates
+            .into_iter()
+            .flatten()
+            .map(|mut new_op| {
+                new_op.id = state.allocate_operator_id();
+                Arc::new(new_op)
+            })
+            .collect();
+        guard_write.extend(imported.iter().cloned());
+        state.rebuild_indexes(&guard_write)?;
+    }
+
+    Ok(ImportOutcome {
+        committed,
+        rows: row_outcomes,
+        imported,
+    })
+}
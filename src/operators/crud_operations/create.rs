@@ -1,7 +1,9 @@
 use crate::app_state::model::AppState;
 use crate::utils::{
+    config::IntegrityMode,
+    countries::resolve_country,
     error_responses::ErrorResponse,
-    models::{CreateOperator, Operator, OperatorDuplicateChecker},
+    models::{CountryRecord, CreateOperator, Operator, OperatorDuplicateChecker},
     validations,
 };
 use std::sync::Arc;
@@ -9,11 +11,23 @@ use std::sync::Arc;
 fn validate_create_input(
     operators: &[Operator],
     input: &CreateOperator,
-    country: &str,
+    country: &CountryRecord,
+    e164_mode: IntegrityMode,
 ) -> Result<(), ErrorResponse> {
-    let _ = country;
     let collected_existing_operator_data = OperatorDuplicateChecker::from_operators(operators);
+    validate_create_fields(&collected_existing_operator_data, input)?;
+    validations::validate_numbering_plan(
+        input.e212.as_deref(),
+        input.realm.as_deref(),
+        &country.alpha2,
+    )?;
+    validations::validate_msisdn_country_codes(input.e164.as_deref(), &country.alpha2, e164_mode)
+}
 
+pub(crate) fn validate_create_fields(
+    collected_existing_operator_data: &OperatorDuplicateChecker<'_>,
+    input: &CreateOperator,
+) -> Result<(), ErrorResponse> {
     validations::validate_unique_imsi_codes(
         input.e212.as_deref(),
         &collected_existing_operator_data.imsi_prefixes,
@@ -24,6 +38,7 @@ fn validate_create_input(
     )?;
     validations::validate_unique_operator_name(
         &input.name,
+        &input.names,
         &collected_existing_operator_data.operator_names,
     )?;
     let tadig_list = input.tadig.clone().unwrap_or_default();
@@ -41,7 +56,15 @@ pub fn create_operator(
     app_data: &AppState,
     input: CreateOperator,
 ) -> Result<Arc<Operator>, ErrorResponse> {
-    let country = validations::format_country_name(&input.country)?;
+    create_operator_with_id(app_data, input, None)
+}
+
+pub(crate) fn create_operator_with_id(
+    app_data: &AppState,
+    input: CreateOperator,
+    id: Option<u64>,
+) -> Result<Arc<Operator>, ErrorResponse> {
+    let country = resolve_country("country", &input.country)?;
 
     let guard_read = app_data
         .operators
@@ -49,20 +72,26 @@ pub fn create_operator(
         .map_err(|_| ErrorResponse::InternalError)?;
     let cloned_operator_list: Vec<Operator> =
         guard_read.iter().map(|arc| (**arc).clone()).collect();
-    let country_operator = validations::find_operator_by_country(&cloned_operator_list, &country)?;
-    validate_create_input(&cloned_operator_list, &input, &country)?;
+    validate_create_input(
+        &cloned_operator_list,
+        &input,
+        country,
+        app_data.e164_validation_mode,
+    )?;
     drop(guard_read);
 
     let tadig_list = input.tadig.clone().unwrap_or_default();
-    let iso2 = country_operator.iso2.clone();
-    let iso3 = country_operator.iso3.clone();
+    let iso2 = country.alpha2.clone();
+    let iso3 = country.alpha3.clone();
     let new_op = Operator {
-        country,
+        id: id.unwrap_or_else(|| app_data.allocate_operator_id()),
+        country: country.name.clone(),
         e164: input.e164.clone(),
         e212: input.e212.clone(),
         iso2,
         iso3,
         name: input.name.clone(),
+        names: input.names.clone(),
         realm: input.realm.clone(),
         tadig: Some(tadig_list),
     };
@@ -75,6 +104,7 @@ pub fn create_operator(
             .write()
             .map_err(|_| ErrorResponse::InternalError)?;
         guard_write.push(Arc::new(new_op));
+        app_data.rebuild_indexes(&guard_write)?;
     }
 
     Ok(new_arc)
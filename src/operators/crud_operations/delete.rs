@@ -1,34 +1,33 @@
 use crate::{
     app_state::AppState,
-    utils::{error_responses::ErrorResponse, models::Operator},
+    utils::{
+        error_responses::ErrorResponse,
+        models::{Operator, OperatorKey},
+        validations::validate_if_match,
+    },
 };
 use std::sync::Arc;
 
-pub fn delete_operator(state: &AppState, tadig_code: &str) -> Result<Arc<Operator>, ErrorResponse> {
+pub fn delete_operator<'k>(
+    state: &AppState,
+    target: impl Into<OperatorKey<'k>>,
+    if_match: Option<&str>,
+) -> Result<Arc<Operator>, ErrorResponse> {
+    let target = target.into();
     let mut operators_collection = state
         .operators
         .write()
         .map_err(|_| ErrorResponse::InternalError)?;
 
-    if let Some((operator_index, _)) =
-        operators_collection
-            .iter()
-            .enumerate()
-            .find(|(_, operator)| {
-                operator.tadig.as_ref().is_some_and(|tadig_codes| {
-                    tadig_codes
-                        .iter()
-                        .any(|cur_tadig_code| cur_tadig_code == tadig_code)
-                })
-            })
+    if let Some(operator_index) = operators_collection
+        .iter()
+        .position(|operator| target.matches(operator))
     {
+        validate_if_match(&operators_collection[operator_index], if_match)?;
         let removed_operator = operators_collection.swap_remove(operator_index);
+        state.rebuild_indexes(&operators_collection)?;
         Ok(removed_operator)
     } else {
-        Err(ErrorResponse::NotFound {
-            field: "tadig".to_string(),
-            received: tadig_code.to_string(),
-            expected: "an existing TADIG code".to_string(),
-        })
+        Err(target.not_found())
     }
 }
@@ -0,0 +1,279 @@
+use crate::utils::{
+    countries::find_country,
+    error_responses::ErrorResponse,
+    models::{
+        Operator, OperatorField, OperatorListing, OperatorValidationError, PageRequest, ReadQuery,
+        SortKey,
+    },
+};
+use serde::{Serialize, Serializer, ser::SerializeMap};
+use std::cmp::Ordering;
+
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+fn invalid_list_param(field: &str, message: String, received: &str) -> ErrorResponse {
+    OperatorValidationError::FieldValidationError {
+        field: field.to_string(),
+        message,
+        received: Some(received.to_string()),
+    }
+    .into()
+}
+
+pub fn parse_fields(raw: &str) -> Result<Vec<OperatorField>, ErrorResponse> {
+    let mut fields = Vec::new();
+    for key in raw.split(',').map(str::trim).filter(|k| !k.is_empty()) {
+        let field = OperatorField::parse(key).ok_or_else(|| {
+            invalid_list_param("fields", format!("Unknown operator field '{}'", key), raw)
+        })?;
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+    if fields.is_empty() {
+        return Err(invalid_list_param(
+            "fields",
+            "fields must name at least one operator field".to_string(),
+            raw,
+        ));
+    }
+    Ok(fields)
+}
+
+pub fn parse_sort(raw: &str) -> Result<Vec<SortKey>, ErrorResponse> {
+    let mut keys = Vec::new();
+    for key in raw.split(',').map(str::trim).filter(|k| !k.is_empty()) {
+        let (descending, name) = match key.strip_prefix('-') {
+            Some(name) => (true, name),
+            None => (false, key),
+        };
+        let field = OperatorField::parse(name).ok_or_else(|| {
+            invalid_list_param("sort", format!("Unknown operator field '{}'", name), raw)
+        })?;
+        if !field.is_sortable() {
+            return Err(invalid_list_param(
+                "sort",
+                format!("Cannot sort by list field '{}'", field.key()),
+                raw,
+            ));
+        }
+        keys.push(SortKey { field, descending });
+    }
+    Ok(keys)
+}
+
+fn validate_page_size(field: &str, size: usize) -> Result<usize, ErrorResponse> {
+    if (1..=MAX_PAGE_SIZE).contains(&size) {
+        return Ok(size);
+    }
+    Err(invalid_list_param(
+        field,
+        format!("{} must be between 1 and {}", field, MAX_PAGE_SIZE),
+        &size.to_string(),
+    ))
+}
+
+pub fn parse_page_request(query: &ReadQuery) -> Result<PageRequest, ErrorResponse> {
+    let cursor_style = query.limit.is_some() || query.cursor.is_some();
+    let page_style = query.page.is_some() || query.per_page.is_some();
+    if cursor_style && page_style {
+        return Err(invalid_list_param(
+            "page",
+            "Use either limit/cursor or page/per_page, not both".to_string(),
+            "",
+        ));
+    }
+    if cursor_style {
+        let limit = validate_page_size("limit", query.limit.unwrap_or(MAX_PAGE_SIZE))?;
+        let offset = match query.cursor.as_deref() {
+            Some(cursor) => cursor.trim().parse().map_err(|_| {
+                invalid_list_param(
+                    "cursor",
+                    "cursor must be a value taken from a previous Link header".to_string(),
+                    cursor,
+                )
+            })?,
+            None => 0,
+        };
+        return Ok(PageRequest::Cursor { offset, limit });
+    }
+    if page_style {
+        let per_page = validate_page_size("per_page", query.per_page.unwrap_or(MAX_PAGE_SIZE))?;
+        let page = query.page.unwrap_or(1);
+        if page == 0 {
+            return Err(invalid_list_param(
+                "page",
+                "page numbers start at 1".to_string(),
+                "0",
+            ));
+        }
+        return Ok(PageRequest::Page { page, per_page });
+    }
+    Ok(PageRequest::All)
+}
+
+fn matches_filters(op: &Operator, query: &ReadQuery) -> bool {
+    let country = query.country.as_deref().map(str::trim);
+    query
+        .iso2
+        .as_deref()
+        .is_none_or(|iso2| op.iso2.eq_ignore_ascii_case(iso2.trim()))
+        && query
+            .iso3
+            .as_deref()
+            .is_none_or(|iso3| op.iso3.eq_ignore_ascii_case(iso3.trim()))
+        && country.is_none_or(|country| match find_country(country) {
+            Some(record) => op.iso2.eq_ignore_ascii_case(&record.alpha2),
+            None => op.country.eq_ignore_ascii_case(country),
+        })
+        && query.realm.as_deref().is_none_or(|realm| {
+            op.realm
+                .iter()
+                .flatten()
+                .any(|r| r.eq_ignore_ascii_case(realm.trim()))
+        })
+        && query.name.as_deref().is_none_or(|needle| {
+            let needle = needle.trim().to_lowercase();
+            op.all_names()
+                .any(|name| name.to_lowercase().contains(&needle))
+        })
+}
+
+fn compare_by(a: &Operator, b: &Operator, field: OperatorField) -> Ordering {
+    match field {
+        OperatorField::Id => a.id.cmp(&b.id),
+        OperatorField::Country => a.country.to_lowercase().cmp(&b.country.to_lowercase()),
+        OperatorField::Iso2 => a.iso2.cmp(&b.iso2),
+        OperatorField::Iso3 => a.iso3.cmp(&b.iso3),
+        OperatorField::Name => a
+            .name
+            .as_deref()
+            .map(str::to_lowercase)
+            .cmp(&b.name.as_deref().map(str::to_lowercase)),
+        _ => Ordering::Equal,
+    }
+}
+
+pub fn list_operators<'a>(
+    operators: &[&'a Operator],
+    query: &ReadQuery,
+) -> Result<OperatorListing<'a>, ErrorResponse> {
+    let sort = match query.sort.as_deref() {
+        Some(raw) => parse_sort(raw)?,
+        None => Vec::new(),
+    };
+    let page = parse_page_request(query)?;
+
+    let mut matched: Vec<&'a Operator> = operators
+        .iter()
+        .copied()
+        .filter(|op| matches_filters(op, query))
+        .collect();
+    if !sort.is_empty() {
+        matched.sort_by(|a, b| {
+            sort.iter().fold(Ordering::Equal, |ordering, key| {
+                ordering.then_with(|| {
+                    let ordering = compare_by(a, b, key.field);
+                    if key.descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                })
+            })
+        });
+    }
+
+    let total = matched.len();
+    let (offset, limit) = match page {
+        PageRequest::All => (0, total),
+        PageRequest::Cursor { offset, limit } => (offset, limit),
+        PageRequest::Page { page, per_page } => ((page - 1).saturating_mul(per_page), per_page),
+    };
+    let operators = matched.into_iter().skip(offset).take(limit).collect();
+    Ok(OperatorListing {
+        operators,
+        total,
+        page,
+    })
+}
+
+fn page_link(path: &str, params: &[(&str, &str)], page: (&str, String), rel: &str) -> String {
+    let mut query: Vec<String> = params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+    query.push(format!("{}={}", page.0, page.1));
+    format!("<{}?{}>; rel=\"{}\"", path, query.join("&"), rel)
+}
+
+pub fn page_links(path: &str, query_string: &str, page: PageRequest, total: usize) -> Vec<String> {
+    let kept: Vec<(&str, &str)> = query_string
+        .split('&')
+        .filter_map(|pair| match pair.split_once('=') {
+            Some((key, value)) => Some((key, value)),
+            None => (!pair.is_empty()).then_some((pair, "")),
+        })
+        .filter(|(key, _)| !matches!(*key, "cursor" | "page"))
+        .collect();
+    let mut links = Vec::new();
+    match page {
+        PageRequest::All => {}
+        PageRequest::Cursor { offset, limit } => {
+            links.push(page_link(path, &kept, ("cursor", "0".to_string()), "first"));
+            if offset > 0 {
+                let previous = offset.saturating_sub(limit).to_string();
+                links.push(page_link(path, &kept, ("cursor", previous), "prev"));
+            }
+            if offset.saturating_add(limit) < total {
+                let next = (offset + limit).to_string();
+                links.push(page_link(path, &kept, ("cursor", next), "next"));
+            }
+        }
+        PageRequest::Page { page, per_page } => {
+            let last = total.div_ceil(per_page).max(1);
+            links.push(page_link(path, &kept, ("page", "1".to_string()), "first"));
+            if page > 1 {
+                let previous = (page - 1).min(last).to_string();
+                links.push(page_link(path, &kept, ("page", previous), "prev"));
+            }
+            if page < last {
+                links.push(page_link(
+                    path,
+                    &kept,
+                    ("page", (page + 1).to_string()),
+                    "next",
+                ));
+            }
+            links.push(page_link(path, &kept, ("page", last.to_string()), "last"));
+        }
+    }
+    links
+}
+
+pub struct ProjectedOperator<'a> {
+    pub operator: &'a Operator,
+    pub fields: &'a [OperatorField],
+}
+
+impl Serialize for ProjectedOperator<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let op = self.operator;
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for field in self.fields {
+            match field {
+                OperatorField::Id => map.serialize_entry("id", &op.id)?,
+                OperatorField::Country => map.serialize_entry("country", &op.country)?,
+                OperatorField::Iso2 => map.serialize_entry("iso2", &op.iso2)?,
+                OperatorField::Iso3 => map.serialize_entry("iso3", &op.iso3)?,
+                OperatorField::Name => map.serialize_entry("name", &op.name)?,
+                OperatorField::Names => map.serialize_entry("names", &op.names)?,
+                OperatorField::E212 => map.serialize_entry("e212", &op.e212)?,
+                OperatorField::E164 => map.serialize_entry("e164", &op.e164)?,
+                OperatorField::Realm => map.serialize_entry("realm", &op.realm)?,
+                OperatorField::Tadig => map.serialize_entry("tadig", &op.tadig)?,
+            }
+        }
+        map.end()
+    }
+}
@@ -1,4 +1,6 @@
+pub mod bulk_import;
 pub mod create;
 pub mod delete;
+pub mod list;
 pub mod read;
 pub mod update;
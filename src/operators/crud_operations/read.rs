@@ -1,82 +1,115 @@
 use std::sync::Arc;
 
 use crate::{
-    app_state::model::AppState,
+    app_state::{
+        model::AppState,
+        prefix_index::{PrefixIndex, PrefixTrie},
+    },
     utils::{
         error_responses::ErrorResponse,
-        models::{Operator, QueryType, SubscriberIdKind},
+        models::{MatchMode, Operator, OperatorKey, OperatorLookup, QueryType, SubscriberIdKind},
+        numbering::normalise_msisdn,
         validations::validate_digits,
     },
 };
 
-fn common_prefix_length(str1: &str, str2: &str) -> usize {
-    str1.chars()
-        .zip(str2.chars())
-        .take_while(|(c1, c2)| c1 == c2)
-        .count()
-}
-
 fn find_operator_with_longest_prefix_match<'a>(
-    operator_list: &'a [Operator],
-    extract_field: impl Fn(&Operator) -> Option<&Vec<String>>,
+    operator_list: &'a [Arc<Operator>],
+    prefix_trie: &PrefixTrie,
     lookup_value: &str,
-) -> Option<&'a Operator> {
-    operator_list
-        .iter()
-        .filter_map(|op| {
-            extract_field(op).and_then(|prefixes| {
-                let best = prefixes
-                    .iter()
-                    .map(|p| common_prefix_length(p, lookup_value))
-                    .max()?;
-                if best > 0 { Some((best, op)) } else { None }
-            })
-        })
-        .max_by_key(|(len, _)| *len)
-        .map(|(_, op)| op)
+    match_mode: MatchMode,
+) -> Option<(&'a Arc<Operator>, usize)> {
+    let best = match match_mode {
+        MatchMode::Strict => prefix_trie.longest_prefix(lookup_value),
+        MatchMode::Fuzzy => prefix_trie.longest_common_prefix(lookup_value),
+    };
+    best.and_then(|(operator_index, matched_length)| {
+        operator_list
+            .get(operator_index)
+            .map(|op| (op, matched_length))
+    })
 }
 
 fn find_operator_by_tadig_exact_match<'a>(
-    operator_list: &'a [Operator],
+    operator_list: &'a [Arc<Operator>],
     tadig_code: &str,
-) -> Option<&'a Operator> {
-    operator_list.iter().find(|op| {
-        op.tadig
-            .as_ref()
-            .is_some_and(|codes| codes.iter().any(|c| c == tadig_code))
-    })
+) -> Option<&'a Arc<Operator>> {
+    operator_list.iter().find(|op| op.has_tadig(tadig_code))
 }
 
-pub fn lookup_operator_by_query(
-    state: &AppState,
+fn prefix_lookup(
+    operator_list: &[Arc<Operator>],
+    prefix_trie: &PrefixTrie,
+    lookup_value: &str,
+    match_mode: MatchMode,
+) -> Option<OperatorLookup> {
+    find_operator_with_longest_prefix_match(operator_list, prefix_trie, lookup_value, match_mode)
+        .map(|(op, matched_length)| OperatorLookup {
+            operator: op.clone(),
+            matched_prefix: Some(lookup_value[..matched_length].to_string()),
+        })
+}
+
+pub fn lookup_operator_in(
+    operator_list: &[Arc<Operator>],
+    prefix_index: &PrefixIndex,
     query_text: &str,
     query_type: QueryType,
-) -> Result<Option<Arc<Operator>>, ErrorResponse> {
-    let guard = state
-        .operators
-        .read()
-        .map_err(|_| ErrorResponse::InternalError)?;
-    let flat: Vec<Operator> = guard.iter().map(|arc| (**arc).clone()).collect();
-
+    match_mode: MatchMode,
+) -> Result<Option<OperatorLookup>, ErrorResponse> {
     let found = match query_type {
         QueryType::Imsi => {
             let imsi = query_text.trim();
             validate_digits(SubscriberIdKind::Imsi, "imsi", imsi)
                 .map_err(ErrorResponse::Validation)?;
-            find_operator_with_longest_prefix_match(&flat, |op| op.e212.as_ref(), imsi)
+            prefix_lookup(operator_list, &prefix_index.e212, imsi, match_mode)
         }
         QueryType::Msisdn => {
-            let msisdn = query_text.trim();
-            validate_digits(SubscriberIdKind::Msisdn, "msisdn", msisdn)
+            let msisdn = normalise_msisdn(query_text);
+            validate_digits(SubscriberIdKind::Msisdn, "msisdn", &msisdn)
                 .map_err(ErrorResponse::Validation)?;
-            find_operator_with_longest_prefix_match(&flat, |op| op.e164.as_ref(), msisdn)
+            prefix_lookup(operator_list, &prefix_index.e164, &msisdn, match_mode)
         }
         QueryType::Tadig => {
             let code = query_text.trim().to_uppercase();
-            find_operator_by_tadig_exact_match(&flat, &code)
+            find_operator_by_tadig_exact_match(operator_list, &code).map(|op| OperatorLookup {
+                operator: op.clone(),
+                matched_prefix: None,
+            })
         }
         QueryType::Iso3 => None,
     };
 
-    Ok(found.map(|op| Arc::new(op.clone())))
+    Ok(found)
+}
+
+pub fn lookup_operator_by_query(
+    state: &AppState,
+    query_text: &str,
+    query_type: QueryType,
+    match_mode: MatchMode,
+) -> Result<Option<OperatorLookup>, ErrorResponse> {
+    let guard = state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    let index = state
+        .prefix_index
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+
+    lookup_operator_in(&guard, &index, query_text, query_type, match_mode)
+}
+
+pub fn find_operator_by_id(state: &AppState, id: u64) -> Result<Arc<Operator>, ErrorResponse> {
+    let key = OperatorKey::Id(id);
+    let operators = state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    operators
+        .iter()
+        .find(|op| key.matches(op))
+        .cloned()
+        .ok_or_else(|| key.not_found())
 }
@@ -1,8 +1,12 @@
 use crate::{
     app_state::model::AppState,
     utils::{
+        countries::resolve_country,
         error_responses::ErrorResponse,
-        models::{CreateOperator, Operator, OperatorDuplicateChecker, PatchOperator},
+        models::{
+            CreateOperator, Operator, OperatorDuplicateChecker, OperatorKey, OperatorUpdate,
+            PatchOperator,
+        },
         validations::{self, determine_updated_codes, validate_iso_fields, validate_patch_fields},
     },
 };
@@ -12,10 +16,7 @@ fn update_validations(
     all_operators: &[Operator],
     target_index: usize,
     new_operator: &CreateOperator,
-    formatted_country: &str,
 ) -> Result<(), ErrorResponse> {
-    validations::find_operator_by_country(all_operators, formatted_country)?;
-
     let mut duplicate_field_checker = OperatorDuplicateChecker::from_operators(all_operators);
     duplicate_field_checker.exclude(&all_operators[target_index]);
 
@@ -29,6 +30,7 @@ fn update_validations(
     )?;
     validations::validate_unique_operator_name(
         &new_operator.name,
+        &new_operator.names,
         &duplicate_field_checker.operator_names,
     )?;
 
@@ -42,12 +44,14 @@ fn update_validations(
     Ok(())
 }
 
-pub fn update_operator_by_put(
+pub fn update_operator_by_put<'k>(
     state: &AppState,
-    target_tadig: &str,
+    target: impl Into<OperatorKey<'k>>,
     updated_fields: CreateOperator,
-) -> Result<Arc<Operator>, ErrorResponse> {
-    let validated_country = validations::format_country_name(&updated_fields.country)?;
+    if_match: Option<&str>,
+) -> Result<OperatorUpdate, ErrorResponse> {
+    let target = target.into();
+    let validated_country = resolve_country("country", &updated_fields.country)?;
     let operator_list: Vec<Arc<Operator>> = {
         let read_guard = state
             .operators
@@ -58,55 +62,70 @@ pub fn update_operator_by_put(
 
     let operator_index = operator_list
         .iter()
-        .position(|op| op.has_tadig(target_tadig))
-        .ok_or_else(|| ErrorResponse::NotFound {
-            field: "tadig".to_string(),
-            received: target_tadig.to_string(),
-            expected: "an existing TADIG code".to_string(),
-        })?;
+        .position(|op| target.matches(op))
+        .ok_or_else(|| target.not_found())?;
+    validations::validate_if_match(&operator_list[operator_index], if_match)?;
 
     let existing_operators: Vec<Operator> =
         operator_list.iter().map(|arc| (**arc).clone()).collect();
-    update_validations(
-        &existing_operators,
-        operator_index,
-        &updated_fields,
-        &validated_country,
+    update_validations(&existing_operators, operator_index, &updated_fields)?;
+    validations::validate_numbering_plan(
+        updated_fields.e212.as_deref(),
+        updated_fields.realm.as_deref(),
+        &validated_country.alpha2,
+    )?;
+    validations::validate_msisdn_country_codes(
+        updated_fields.e164.as_deref(),
+        &validated_country.alpha2,
+        state.e164_validation_mode,
     )?;
-
-    let (matched_country_iso2, matched_country_iso3) = {
-        let country_operator =
-            validations::find_operator_by_country(&existing_operators, &validated_country)?;
-        (country_operator.iso2.clone(), country_operator.iso3.clone())
-    };
 
     let fully_updated_operator = Operator {
-        country: validated_country,
+        id: operator_list[operator_index].id,
+        country: validated_country.name.clone(),
         e164: updated_fields.e164,
         e212: updated_fields.e212,
-        iso2: matched_country_iso2,
-        iso3: matched_country_iso3,
+        iso2: validated_country.alpha2.clone(),
+        iso3: validated_country.alpha3.clone(),
         name: updated_fields.name,
+        names: updated_fields.names,
         realm: updated_fields.realm,
         tadig: Some(updated_fields.tadig.unwrap_or_default()),
     };
 
+    let updated = Arc::new(fully_updated_operator);
     {
         let mut write_guard = state
             .operators
             .write()
             .map_err(|_| ErrorResponse::InternalError)?;
-        write_guard[operator_index] = Arc::new(fully_updated_operator.clone());
+        let unchanged = write_guard
+            .get(operator_index)
+            .is_some_and(|current| Arc::ptr_eq(current, &operator_list[operator_index]));
+        if !unchanged {
+            return Err(ErrorResponse::PreconditionFailed {
+                field: "operator".to_string(),
+                received: operator_list[operator_index].etag(),
+                expected: "an operator left unchanged while the update was validated".to_string(),
+            });
+        }
+        write_guard[operator_index] = updated.clone();
+        state.rebuild_indexes(&write_guard)?;
     }
 
-    Ok(Arc::new(fully_updated_operator))
+    Ok(OperatorUpdate {
+        previous: operator_list[operator_index].clone(),
+        updated,
+    })
 }
 
-pub fn update_operator_by_patch(
+pub fn update_operator_by_patch<'k>(
     state: &AppState,
-    target_tadig: &str,
+    target: impl Into<OperatorKey<'k>>,
     patch_data: PatchOperator,
-) -> Result<Arc<Operator>, ErrorResponse> {
+    if_match: Option<&str>,
+) -> Result<OperatorUpdate, ErrorResponse> {
+    let target = target.into();
     validate_iso_fields(&patch_data)?;
 
     let mut operator_store = state
@@ -115,47 +134,66 @@ pub fn update_operator_by_patch(
         .map_err(|_| ErrorResponse::InternalError)?;
     let operator_index = operator_store
         .iter()
-        .position(|op| op.has_tadig(target_tadig))
-        .ok_or_else(|| ErrorResponse::NotFound {
-            field: "tadig".to_string(),
-            received: target_tadig.to_string(),
-            expected: "an existing TADIG code".to_string(),
-        })?;
-
-    let operator_store_clone: Vec<Arc<Operator>> = operator_store.clone();
-    let mut cloned_plain_operators: Vec<Operator> = operator_store_clone
-        .iter()
-        .map(|arc| (**arc).clone())
-        .collect();
+        .position(|op| target.matches(op))
+        .ok_or_else(|| target.not_found())?;
+    validations::validate_if_match(&operator_store[operator_index], if_match)?;
+
+    let current = operator_store[operator_index].clone();
+    let mut cloned_plain_operators: Vec<Operator> =
+        operator_store.iter().map(|arc| (**arc).clone()).collect();
     cloned_plain_operators.remove(operator_index);
     let duplicate_field_checker = OperatorDuplicateChecker::from_operators(&cloned_plain_operators);
 
-    let operator_entry = &mut operator_store[operator_index];
-    let mutable_operator = Arc::make_mut(operator_entry);
-
     let (resolved_country, matched_country_iso2, matched_country_iso3) =
-        determine_updated_codes(&operator_store_clone, &patch_data, mutable_operator)?;
-    validations::format_country_name(&resolved_country)?;
+        determine_updated_codes(&patch_data, &current)?;
     validate_patch_fields(&duplicate_field_checker, &patch_data)?;
+    let country_changed = matched_country_iso2 != current.iso2;
+    if patch_data.e164.is_some() || country_changed {
+        validations::validate_msisdn_country_codes(
+            patch_data.e164.as_deref().or(current.e164.as_deref()),
+            &matched_country_iso2,
+            state.e164_validation_mode,
+        )?;
+    }
+    let touches_numbering =
+        patch_data.e212.is_some() || patch_data.realm.is_some() || country_changed;
+    if touches_numbering {
+        validations::validate_numbering_plan(
+            patch_data.e212.as_deref().or(current.e212.as_deref()),
+            patch_data.realm.as_deref().or(current.realm.as_deref()),
+            &matched_country_iso2,
+        )?;
+    }
 
-    mutable_operator.country = resolved_country;
-    mutable_operator.iso2 = matched_country_iso2;
-    mutable_operator.iso3 = matched_country_iso3;
+    let mut patched = (*current).clone();
+    patched.country = resolved_country;
+    patched.iso2 = matched_country_iso2;
+    patched.iso3 = matched_country_iso3;
     if let Some(e212_list) = patch_data.e212 {
-        mutable_operator.e212 = Some(e212_list);
+        patched.e212 = Some(e212_list);
     }
     if let Some(e164_list) = patch_data.e164 {
-        mutable_operator.e164 = Some(e164_list);
+        patched.e164 = Some(e164_list);
     }
     if let Some(name_opt) = patch_data.name {
-        mutable_operator.name = name_opt;
+        patched.name = name_opt;
+    }
+    if let Some(names) = patch_data.names {
+        patched.names = Some(names);
     }
     if let Some(realm) = patch_data.realm {
-        mutable_operator.realm = Some(realm);
+        patched.realm = Some(realm);
     }
     if let Some(tadig) = patch_data.tadig {
-        mutable_operator.tadig = Some(tadig);
+        patched.tadig = Some(tadig);
     }
 
-    Ok(operator_store[operator_index].clone())
+    let updated = Arc::new(patched);
+    operator_store[operator_index] = updated.clone();
+    state.rebuild_indexes(&operator_store)?;
+
+    Ok(OperatorUpdate {
+        previous: current,
+        updated,
+    })
 }
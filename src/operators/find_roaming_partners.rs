@@ -1,20 +1,30 @@
 use crate::app_state::model::{AppState, CountryBordersMap};
 use crate::utils::error_responses::ErrorResponse;
-use crate::utils::models::{Operator, RoamingPartnersResult};
+use crate::utils::models::{
+    BorderCountry, ContractFilter, Operator, OperatorValidationError, RoamingHopGroup,
+    RoamingPartner, RoamingPartnersResult,
+};
+use chrono::Utc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
-fn find_base_country_by_tadig(
-    operators: &[Operator],
+pub const MAX_ROAMING_HOPS: u32 = 5;
+
+fn find_base_operator_by_tadig<'a>(
+    operators: &'a [Arc<Operator>],
     requested_tadig: &str,
-) -> Result<String, ErrorResponse> {
+) -> Result<&'a Operator, ErrorResponse> {
     operators
         .iter()
         .find(|op| {
             op.tadig
-                .as_ref()
-                .map(|tads| tads.iter().any(|t| t.eq_ignore_ascii_case(requested_tadig)))
-                .unwrap_or(false)
+                .iter()
+                .flatten()
+                .any(|t| t.eq_ignore_ascii_case(requested_tadig))
         })
-        .map(|op| op.country.clone())
+        .map(|op| &**op)
         .ok_or_else(|| ErrorResponse::NotFound {
             field: "tadig".to_string(),
             received: requested_tadig.to_string(),
@@ -22,78 +32,210 @@ fn find_base_country_by_tadig(
         })
 }
 
-fn lookup_neighbor_names(
+fn validate_max_hops(max_hops: u32) -> Result<(), ErrorResponse> {
+    if (1..=MAX_ROAMING_HOPS).contains(&max_hops) {
+        return Ok(());
+    }
+    Err(OperatorValidationError::FieldValidationError {
+        field: "max_hops".to_string(),
+        message: format!("max_hops must be between 1 and {}", MAX_ROAMING_HOPS),
+        received: Some(max_hops.to_string()),
+    }
+    .into())
+}
+
+fn countries_within_hops(
     borders_map: &CountryBordersMap,
-    base_country: &str,
-) -> Result<Vec<String>, ErrorResponse> {
-    if let Some(neighbors) = borders_map.get(base_country) {
-        return Ok(neighbors.clone());
+    base_iso2: &str,
+    max_hops: u32,
+) -> Result<Vec<RoamingHopGroup>, ErrorResponse> {
+    let direct =
+        borders_map
+            .get(base_iso2)
+            .ok_or_else(|| ErrorResponse::BorderCountryNotFound {
+                field: "iso2".to_string(),
+                received: base_iso2.to_string(),
+                expected: "a country with known borders".to_string(),
+            })?;
+
+    let mut visited: Vec<&str> = vec![base_iso2];
+    let mut frontier: Vec<&BorderCountry> = Vec::new();
+    for neighbor in direct {
+        if !visited.contains(&neighbor.iso2.as_str()) {
+            visited.push(&neighbor.iso2);
+            frontier.push(neighbor);
+        }
     }
-    let lc_base = base_country.to_lowercase();
-    for (csv_country, neighbors) in borders_map.iter() {
-        if lc_base.contains(&csv_country.to_lowercase()) {
-            return Ok(neighbors.clone());
+
+    let mut groups = Vec::new();
+    for hops in 1..=max_hops {
+        if frontier.is_empty() {
+            break;
         }
+        let mut next = Vec::new();
+        for country in &frontier {
+            for neighbor in borders_map.get(&country.iso2).into_iter().flatten() {
+                if !visited.contains(&neighbor.iso2.as_str()) {
+                    visited.push(&neighbor.iso2);
+                    next.push(neighbor);
+                }
+            }
+        }
+        groups.push(RoamingHopGroup {
+            hops,
+            countries: frontier.into_iter().cloned().collect(),
+            partners: 0,
+        });
+        frontier = next;
     }
-    Err(ErrorResponse::BorderCountryNotFound {
-        field: "country".to_string(),
-        received: base_country.to_string(),
-        expected: "a country with known borders".to_string(),
+    Ok(groups)
+}
+
+fn contracted_tadigs(state: &AppState, base: &Operator) -> Result<HashSet<String>, ErrorResponse> {
+    let today = Utc::now().date_naive();
+    let agreements = state
+        .agreements
+        .entries
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    let mut counterparts = HashSet::new();
+    for agreement in agreements.iter().filter(|a| a.in_force_on(today)) {
+        for tadig in base.tadig.iter().flatten() {
+            if agreement.home_tadig.eq_ignore_ascii_case(tadig) {
+                counterparts.insert(agreement.partner_tadig.to_ascii_uppercase());
+            } else if agreement.partner_tadig.eq_ignore_ascii_case(tadig) {
+                counterparts.insert(agreement.home_tadig.to_ascii_uppercase());
+            }
+        }
+    }
+    Ok(counterparts)
+}
+
+fn roaming_partner(
+    op: &Operator,
+    hops: u32,
+    contracted: &HashSet<String>,
+    filter: ContractFilter,
+) -> Option<RoamingPartner> {
+    let is_contracted = op
+        .tadig
+        .iter()
+        .flatten()
+        .any(|t| contracted.contains(&t.to_ascii_uppercase()));
+    let keep = match filter {
+        ContractFilter::Contracted => is_contracted,
+        ContractFilter::Uncontracted => !is_contracted,
+        ContractFilter::All => true,
+    };
+    keep.then(|| RoamingPartner {
+        hops,
+        contracted: is_contracted,
+        operator: op.clone(),
     })
 }
 
-fn collect_partners_by_country(
-    operators: &[Operator],
-    neighbor_names_lc: &[String],
-) -> Vec<Operator> {
-    operators
+fn collect_partners_by_hops(
+    operators: &[Arc<Operator>],
+    groups: &mut [RoamingHopGroup],
+    contracted: &HashSet<String>,
+    filter: ContractFilter,
+) -> Vec<RoamingPartner> {
+    let hops_by_iso2: HashMap<String, u32> = groups
         .iter()
-        .filter_map(|op| {
-            let cand_country_lc = op.country.to_lowercase();
-            if neighbor_names_lc
+        .flat_map(|group| {
+            group
+                .countries
                 .iter()
-                .any(|nbr| cand_country_lc.contains(nbr))
-            {
-                Some(op.clone())
-            } else {
-                None
-            }
+                .map(move |country| (country.iso2.clone(), group.hops))
+        })
+        .collect();
+
+    let mut partners: Vec<RoamingPartner> = operators
+        .iter()
+        .filter_map(|op| {
+            hops_by_iso2
+                .get(&op.iso2.to_ascii_uppercase())
+                .and_then(|&hops| roaming_partner(op, hops, contracted, filter))
         })
+        .collect();
+    partners.sort_by(|a, b| {
+        a.hops
+            .cmp(&b.hops)
+            .then_with(|| a.operator.country.cmp(&b.operator.country))
+            .then_with(|| a.operator.name.cmp(&b.operator.name))
+    });
+    for group in groups.iter_mut() {
+        group.partners = partners.iter().filter(|p| p.hops == group.hops).count();
+    }
+    partners
+}
+
+fn domestic_partners(
+    operators: &[Arc<Operator>],
+    base: &Operator,
+    contracted: &HashSet<String>,
+    filter: ContractFilter,
+) -> Vec<RoamingPartner> {
+    operators
+        .iter()
+        .filter(|op| op.iso2.eq_ignore_ascii_case(&base.iso2) && op.id != base.id)
+        .filter_map(|op| roaming_partner(op, 0, contracted, filter))
         .collect()
 }
 
 pub fn find_roaming_partners(
     state: &AppState,
     requested_tadig: &str,
+    max_hops: u32,
+    filter: ContractFilter,
 ) -> Result<RoamingPartnersResult, ErrorResponse> {
-    let base_country: String = {
-        let operators_read_guard = state
-            .operators
+    validate_max_hops(max_hops)?;
+    let operators = state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    let base = find_base_operator_by_tadig(&operators, requested_tadig)?;
+    let base_iso2 = base.iso2.to_ascii_uppercase();
+    let mut groups = {
+        let borders = state
+            .borders
             .read()
             .map_err(|_| ErrorResponse::InternalError)?;
-        let operators_list: Vec<Operator> = operators_read_guard
-            .iter()
-            .map(|arc| (**arc).clone())
-            .collect();
-        find_base_country_by_tadig(&operators_list, requested_tadig)?
-    };
-    let neighbor_names = lookup_neighbor_names(&state.borders, &base_country)?;
-    let neighbor_names_lc: Vec<String> = neighbor_names.iter().map(|n| n.to_lowercase()).collect();
-    let partners: Vec<Operator> = {
-        let operators_read_guard = state
-            .operators
-            .read()
-            .map_err(|_| ErrorResponse::InternalError)?;
-        let operators_list: Vec<Operator> = operators_read_guard
-            .iter()
-            .map(|arc| (**arc).clone())
-            .collect();
-        collect_partners_by_country(&operators_list, &neighbor_names_lc)
+        countries_within_hops(&borders, &base_iso2, max_hops)?
     };
+    let contracted = contracted_tadigs(state, base)?;
+
+    if groups.is_empty() {
+        let partners = domestic_partners(&operators, base, &contracted, filter);
+        let message = format!(
+            "{} has no land borders; found {} domestic roaming partners",
+            base.country,
+            partners.len()
+        );
+        let by_distance = vec![RoamingHopGroup {
+            hops: 0,
+            countries: vec![BorderCountry {
+                iso2: base_iso2,
+                name: base.country.clone(),
+            }],
+            partners: partners.len(),
+        }];
+        return Ok(RoamingPartnersResult {
+            message,
+            max_hops,
+            bordering_countries: Vec::new(),
+            partners,
+            by_distance,
+        });
+    }
+
+    let partners = collect_partners_by_hops(&operators, &mut groups, &contracted, filter);
     let message = format!("Found {} roaming partners", partners.len());
     Ok(RoamingPartnersResult {
         message,
-        bordering_countries: neighbor_names,
+        max_hops,
+        bordering_countries: groups[0].countries.iter().map(|c| c.name.clone()).collect(),
         partners,
+        by_distance: groups,
     })
 }
@@ -1,48 +1,123 @@
 use crate::{
     app_state::model::AppState,
     utils::{
+        config::{Config, SizeBasis, SizeTier},
+        countries::find_country_by_alpha3,
         error_responses::ErrorResponse,
-        models::{Operator, OperatorSizeGroups},
+        models::{Operator, OperatorSizeGroups, SizeQuery, SizeTierSummary},
     },
 };
-use std::sync::{Arc, RwLockReadGuard};
-
-const SMALL_E212_MAX: usize = 3;
-const MEDIUM_E212_MAX: usize = 7;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLockReadGuard},
+};
 
-fn classify_single_operator(groups: &mut OperatorSizeGroups, operator: Operator) {
-    let e212_count = operator.e212.as_ref().map(|v| v.len()).unwrap_or(0);
+fn count_codes(codes: &Option<Vec<String>>) -> f64 {
+    codes.as_ref().map(|v| v.len()).unwrap_or(0) as f64
+}
 
-    if e212_count <= SMALL_E212_MAX {
-        groups.small_operators.push(operator);
-        groups.small_size_operators += 1;
-    } else if e212_count <= MEDIUM_E212_MAX {
-        groups.medium_operators.push(operator);
-        groups.medium_size_operators += 1;
-    } else {
-        groups.large_operators.push(operator);
-        groups.large_size_operators += 1;
+fn size_score(operator: &Operator, basis: SizeBasis, cfg: &Config) -> f64 {
+    match basis {
+        SizeBasis::E212 => count_codes(&operator.e212),
+        SizeBasis::E164 => count_codes(&operator.e164),
+        SizeBasis::Weighted => {
+            count_codes(&operator.e212) * cfg.size_weight_e212
+                + count_codes(&operator.e164) * cfg.size_weight_e164
+        }
     }
 }
 
-fn categorize_operators_by_e212_count(operators: &[Arc<Operator>]) -> OperatorSizeGroups {
-    operators
+fn tier_for_score(tiers: &[SizeTier], score: f64) -> usize {
+    tiers
+        .iter()
+        .position(|tier| tier.max_score.is_none_or(|max| score <= max))
+        .unwrap_or(tiers.len() - 1)
+}
+
+fn resolve_iso3_filter(iso3: Option<&str>) -> Result<Option<String>, ErrorResponse> {
+    iso3.map(str::trim)
+        .filter(|code| !code.is_empty())
+        .map(|code| {
+            find_country_by_alpha3(code)
+                .map(|country| country.alpha3.clone())
+                .ok_or_else(|| ErrorResponse::NotFound {
+                    field: "iso3".to_string(),
+                    received: code.to_string(),
+                    expected: "an ISO3 code from the ISO 3166-1 table".to_string(),
+                })
+        })
+        .transpose()
+}
+
+fn categorize_operators_by_size(
+    operators: &[Arc<Operator>],
+    cfg: &Config,
+    basis: SizeBasis,
+    iso3: Option<&str>,
+    summary_only: bool,
+) -> (Vec<SizeTierSummary>, Vec<Vec<Arc<Operator>>>) {
+    let tiers = &cfg.size_tiers;
+    let mut summaries: Vec<SizeTierSummary> = tiers
         .iter()
-        .map(|arc_operator| (**arc_operator).clone())
-        .fold(OperatorSizeGroups::default(), |mut groups, operator| {
-            classify_single_operator(&mut groups, operator);
-            groups
+        .map(|tier| SizeTierSummary {
+            name: tier.name.clone(),
+            max_score: tier.max_score,
+            operators: 0,
         })
+        .collect();
+    let mut members: Vec<Vec<Arc<Operator>>> = vec![Vec::new(); tiers.len()];
+
+    for operator in operators
+        .iter()
+        .filter(|op| iso3.is_none_or(|code| op.iso3.eq_ignore_ascii_case(code)))
+    {
+        let tier = tier_for_score(tiers, size_score(operator, basis, cfg));
+        summaries[tier].operators += 1;
+        if !summary_only {
+            members[tier].push(operator.clone());
+        }
+    }
+    (summaries, members)
 }
 
-pub fn get_operators_grouped_by_e212_count(
+pub fn get_operators_grouped_by_size(
     state: &AppState,
+    cfg: &Config,
+    query: &SizeQuery,
 ) -> Result<OperatorSizeGroups, ErrorResponse> {
+    let basis = query.basis.unwrap_or(cfg.size_basis);
+    let iso3 = resolve_iso3_filter(query.iso3.as_deref())?;
     let operators_guard: RwLockReadGuard<'_, Vec<Arc<Operator>>> = state
         .operators
         .read()
         .map_err(|_| ErrorResponse::InternalError)?;
-    let groups = categorize_operators_by_e212_count(&operators_guard);
+    let (tiers, members) = categorize_operators_by_size(
+        &operators_guard,
+        cfg,
+        basis,
+        iso3.as_deref(),
+        query.summary_only,
+    );
+
+    let counts: BTreeMap<String, usize> = tiers
+        .iter()
+        .map(|tier| (format!("{}_size_operators", tier.name), tier.operators))
+        .collect();
+    let members: BTreeMap<String, Vec<Arc<Operator>>> = if query.summary_only {
+        BTreeMap::new()
+    } else {
+        tiers
+            .iter()
+            .zip(members)
+            .map(|(tier, operators)| (format!("{}_operators", tier.name), operators))
+            .collect()
+    };
 
-    Ok(groups)
+    Ok(OperatorSizeGroups {
+        basis,
+        iso3,
+        tiers,
+        counts,
+        members,
+    })
 }
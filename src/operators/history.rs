@@ -0,0 +1,82 @@
+use crate::{
+    app_state::model::AppState,
+    operators::crud_operations::{create::create_operator_with_id, update::update_operator_by_put},
+    utils::{
+        error_responses::ErrorResponse,
+        models::{
+            CreateOperator, Operator, OperatorKey, OperatorValidationError, OperatorVersion,
+            RestoredOperator,
+        },
+    },
+};
+
+fn snapshot_to_input(snapshot: &Operator) -> CreateOperator {
+    CreateOperator {
+        country: snapshot.country.clone(),
+        e164: snapshot.e164.clone(),
+        e212: snapshot.e212.clone(),
+        name: snapshot.name.clone(),
+        names: snapshot.names.clone(),
+        realm: snapshot.realm.clone(),
+        tadig: snapshot.tadig.clone(),
+    }
+}
+
+pub fn get_operator_history<'k>(
+    state: &AppState,
+    key: impl Into<OperatorKey<'k>>,
+) -> Result<Vec<OperatorVersion>, ErrorResponse> {
+    let key = key.into();
+    let versions = state.history.versions_for(key)?;
+    if versions.is_empty() {
+        return Err(key.not_found());
+    }
+    Ok(versions)
+}
+
+pub fn restore_operator_version<'k>(
+    state: &AppState,
+    key: impl Into<OperatorKey<'k>>,
+    version: u32,
+) -> Result<RestoredOperator, ErrorResponse> {
+    let versions = get_operator_history(state, key)?;
+
+    let target = versions
+        .iter()
+        .find(|entry| entry.version == version)
+        .ok_or_else(|| ErrorResponse::NotFound {
+            field: "version".to_string(),
+            received: version.to_string(),
+            expected: format!("a version between 1 and {}", versions.len()),
+        })?;
+    let snapshot =
+        target
+            .operator
+            .as_ref()
+            .ok_or_else(|| OperatorValidationError::FieldValidationError {
+                field: "version".to_string(),
+                message: "This version records a deletion and cannot be restored".to_string(),
+                received: Some(version.to_string()),
+            })?;
+    let input = snapshot_to_input(snapshot);
+
+    match versions.last().and_then(|latest| latest.operator.as_ref()) {
+        Some(current) => {
+            let update = update_operator_by_put(state, current.id, input, None)?;
+            Ok(RestoredOperator {
+                lineage: target.lineage,
+                previous: Some(update.previous),
+                restored: update.updated,
+            })
+        }
+        None => Ok(RestoredOperator {
+            lineage: target.lineage,
+            previous: None,
+            restored: create_operator_with_id(
+                state,
+                input,
+                Some(snapshot.id).filter(|id| *id != 0),
+            )?,
+        }),
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    app_state::model::AppState,
+    utils::{
+        config::IntegrityMode,
+        error_responses::ErrorResponse,
+        models::{IntegrityIssue, IntegrityIssueKind, IntegrityReport, Operator},
+        numbering::{check_calling_code, check_mcc_country, check_realm, parse_e212},
+        validations::TADIG_RE,
+    },
+};
+use log::warn;
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, BTreeSet},
+};
+
+fn collect_duplicates(
+    issues: &mut Vec<IntegrityIssue>,
+    kind: IntegrityIssueKind,
+    label: &str,
+    owners: BTreeMap<String, Vec<u64>>,
+) {
+    for (value, operators) in owners {
+        if operators.len() > 1 {
+            issues.push(IntegrityIssue {
+                kind,
+                message: format!(
+                    "{} '{}' is used by {} operators",
+                    label,
+                    value,
+                    operators.len()
+                ),
+                value,
+                operators,
+            });
+        }
+    }
+}
+
+fn collect_iso_mismatches(
+    issues: &mut Vec<IntegrityIssue>,
+    label: &str,
+    variants: BTreeMap<String, BTreeMap<String, Vec<u64>>>,
+) {
+    for (value, seen) in variants {
+        if seen.len() > 1 {
+            let listed: Vec<&str> = seen.keys().map(String::as_str).collect();
+            issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::IsoMismatch,
+                message: format!(
+                    "{} '{}' maps to conflicting codes: {}",
+                    label,
+                    value,
+                    listed.join(", ")
+                ),
+                value,
+                operators: seen.into_values().flatten().collect(),
+            });
+        }
+    }
+}
+
+fn collect_numbering_issues(issues: &mut Vec<IntegrityIssue>, op: &Operator) {
+    let mut parsed = Vec::new();
+    for code in op.e212.iter().flatten() {
+        let Some(e212_code) = parse_e212(code) else {
+            issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::MalformedE212,
+                value: code.clone(),
+                operators: vec![op.id],
+                message: format!(
+                    "E.212 code '{}' must be a 3-digit MCC followed by a 2- or 3-digit MNC",
+                    code
+                ),
+            });
+            continue;
+        };
+        if let Err(message) = check_mcc_country(&e212_code.mcc, &op.iso2) {
+            issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::NumberingMismatch,
+                value: code.clone(),
+                operators: vec![op.id],
+                message,
+            });
+        }
+        parsed.push(e212_code);
+    }
+    for realm in op.realm.iter().flatten() {
+        if let Err(message) = check_realm(realm, &parsed, &op.iso2) {
+            issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::NumberingMismatch,
+                value: realm.clone(),
+                operators: vec![op.id],
+                message,
+            });
+        }
+    }
+    for code in op.e164.iter().flatten() {
+        if let Err(message) = check_calling_code(code, &op.iso2) {
+            issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::CallingCodeMismatch,
+                value: code.clone(),
+                operators: vec![op.id],
+                message,
+            });
+        }
+    }
+}
+
+pub fn check_dataset_integrity<O: Borrow<Operator>>(operators: &[O]) -> IntegrityReport {
+    let mut issues = Vec::new();
+    let mut ids: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    let mut tadigs: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    let mut e212: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    let mut e164: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    let mut by_country: BTreeMap<String, BTreeMap<String, Vec<u64>>> = BTreeMap::new();
+    let mut by_iso2: BTreeMap<String, BTreeMap<String, Vec<u64>>> = BTreeMap::new();
+    let mut by_iso3: BTreeMap<String, BTreeMap<String, Vec<u64>>> = BTreeMap::new();
+
+    for op in operators.iter().map(Borrow::borrow) {
+        if op.id != 0 {
+            ids.entry(op.id.to_string()).or_default().push(op.id);
+        }
+        let mut own_tadigs = BTreeSet::new();
+        for code in op.tadig.iter().flatten() {
+            if !TADIG_RE.is_match(code) {
+                issues.push(IntegrityIssue {
+                    kind: IntegrityIssueKind::MalformedTadig,
+                    value: code.clone(),
+                    operators: vec![op.id],
+                    message: format!(
+                        "TADIG code '{}' must be 5-9 letters, digits or underscores",
+                        code
+                    ),
+                });
+            }
+            if own_tadigs.insert(code.to_uppercase()) {
+                tadigs.entry(code.to_uppercase()).or_default().push(op.id);
+            }
+        }
+        collect_numbering_issues(&mut issues, op);
+        for code in op.e212.iter().flatten().collect::<BTreeSet<_>>() {
+            e212.entry(code.clone()).or_default().push(op.id);
+        }
+        for code in op.e164.iter().flatten().collect::<BTreeSet<_>>() {
+            e164.entry(code.clone()).or_default().push(op.id);
+        }
+        by_country
+            .entry(op.country.to_lowercase())
+            .or_default()
+            .entry(format!("{}/{}", op.iso2, op.iso3))
+            .or_default()
+            .push(op.id);
+        by_iso2
+            .entry(op.iso2.clone())
+            .or_default()
+            .entry(op.iso3.clone())
+            .or_default()
+            .push(op.id);
+        by_iso3
+            .entry(op.iso3.clone())
+            .or_default()
+            .entry(op.iso2.clone())
+            .or_default()
+            .push(op.id);
+    }
+
+    collect_duplicates(
+        &mut issues,
+        IntegrityIssueKind::DuplicateId,
+        "Operator id",
+        ids,
+    );
+    collect_duplicates(
+        &mut issues,
+        IntegrityIssueKind::DuplicateTadig,
+        "TADIG code",
+        tadigs,
+    );
+    collect_duplicates(
+        &mut issues,
+        IntegrityIssueKind::DuplicateE212,
+        "E.212 prefix",
+        e212,
+    );
+    collect_duplicates(
+        &mut issues,
+        IntegrityIssueKind::DuplicateE164,
+        "E.164 prefix",
+        e164,
+    );
+    collect_iso_mismatches(&mut issues, "Country", by_country);
+    collect_iso_mismatches(&mut issues, "ISO2", by_iso2);
+    collect_iso_mismatches(&mut issues, "ISO3", by_iso3);
+
+    IntegrityReport {
+        operators_checked: operators.len(),
+        clean: issues.is_empty(),
+        issues,
+    }
+}
+
+pub fn is_enforced(issue: &IntegrityIssue, e164_mode: IntegrityMode) -> bool {
+    issue.kind != IntegrityIssueKind::CallingCodeMismatch || e164_mode == IntegrityMode::Strict
+}
+
+pub fn enforce_integrity(
+    report: &IntegrityReport,
+    mode: IntegrityMode,
+    e164_mode: IntegrityMode,
+) -> Result<(), String> {
+    for issue in &report.issues {
+        warn!("Integrity check: {}", issue.message);
+    }
+    let enforced = report
+        .issues
+        .iter()
+        .filter(|issue| is_enforced(issue, e164_mode))
+        .count();
+    if mode == IntegrityMode::Strict && enforced > 0 {
+        return Err(format!(
+            "Operator mappings failed the integrity check with {} issues",
+            enforced
+        ));
+    }
+    Ok(())
+}
+
+pub fn get_integrity_report(state: &AppState) -> Result<IntegrityReport, ErrorResponse> {
+    let operators = state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    Ok(check_dataset_integrity(&operators))
+}
@@ -0,0 +1,61 @@
+use crate::{
+    app_state::model::AppState,
+    utils::{
+        error_responses::ErrorResponse,
+        models::{MccListing, MncEntry, OperatorValidationError},
+        numbering::{countries_for_mcc, is_mcc, parse_e212},
+    },
+};
+
+pub fn list_mncs(state: &AppState, mcc: &str) -> Result<MccListing, ErrorResponse> {
+    let mcc = mcc.trim();
+    if !is_mcc(mcc) {
+        return Err(OperatorValidationError::FieldValidationError {
+            field: "mcc".to_string(),
+            message: "MCC must be exactly 3 digits".to_string(),
+            received: Some(mcc.to_string()),
+        }
+        .into());
+    }
+    let countries = countries_for_mcc(mcc);
+    if countries.is_empty() {
+        return Err(ErrorResponse::NotFound {
+            field: "mcc".to_string(),
+            received: mcc.to_string(),
+            expected: "an MCC from the bundled E.212 table".to_string(),
+        });
+    }
+
+    let operators = state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    let mut mncs: Vec<MncEntry> = operators
+        .iter()
+        .flat_map(|op| {
+            op.e212
+                .iter()
+                .flatten()
+                .filter_map(move |code| Some((parse_e212(code)?, code, op)))
+        })
+        .filter(|(parsed, _, _)| parsed.mcc == mcc)
+        .map(|(parsed, code, op)| MncEntry {
+            mnc: parsed.mnc,
+            e212: code.clone(),
+            operator: op.clone(),
+        })
+        .collect();
+    mncs.sort_by_key(|entry| {
+        (
+            entry.mnc.parse::<u16>().unwrap_or_default(),
+            entry.mnc.len(),
+            entry.operator.id,
+        )
+    });
+
+    Ok(MccListing {
+        mcc: mcc.to_string(),
+        countries: countries.to_vec(),
+        mncs,
+    })
+}
@@ -1,5 +1,13 @@
+pub mod audit;
+pub mod batch_lookup;
 pub mod crud_operations;
 pub mod find_roaming_partners;
 pub mod get_operators_by_e212_count;
 pub mod group_operators_by_iso3;
+pub mod history;
+pub mod integrity;
+pub mod mcc;
 pub mod network_names;
+pub mod roaming_agreements;
+pub mod search;
+pub mod steering;
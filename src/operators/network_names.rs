@@ -1,30 +1,84 @@
 use crate::{
     app_state::AppState,
-    utils::{error_responses::ErrorResponse, validations::format_network_name},
+    utils::{
+        error_responses::ErrorResponse,
+        models::{NameKind, Operator, OperatorValidationError},
+        validations::format_network_name,
+    },
 };
+use std::collections::BTreeMap;
 
-pub fn retrieve_network_names(app_state: &AppState) -> Result<Vec<String>, ErrorResponse> {
+pub const PRIMARY_NAME_KEY: &str = "name";
+
+fn names_of_kind(op: &Operator, kind: Option<NameKind>) -> Vec<&str> {
+    match kind {
+        None => op.name.iter().map(String::as_str).collect(),
+        Some(kind) => op
+            .names
+            .iter()
+            .flatten()
+            .filter(|n| n.kind == kind)
+            .map(|n| n.value.as_str())
+            .collect(),
+    }
+}
+
+fn sorted_names<'a>(names: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut names: Vec<String> = names.map(format_network_name).collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+pub fn parse_name_kind(label: &str) -> Result<Option<NameKind>, ErrorResponse> {
+    if label.trim().eq_ignore_ascii_case(PRIMARY_NAME_KEY) {
+        return Ok(None);
+    }
+    NameKind::parse(label).map(Some).ok_or_else(|| {
+        OperatorValidationError::FieldValidationError {
+            field: "type".to_string(),
+            message: "type must be one of name, legal, brand, short or historic".to_string(),
+            received: Some(label.to_string()),
+        }
+        .into()
+    })
+}
+
+pub fn retrieve_network_names(
+    app_state: &AppState,
+    kind: Option<NameKind>,
+) -> Result<Vec<String>, ErrorResponse> {
     let guard = app_state
         .operators
         .read()
         .map_err(|_| ErrorResponse::InternalError)?;
 
-    let mut names: Vec<String> = guard
-        .iter()
-        .filter_map(|op| op.name.as_ref())
-        .map(|n| format_network_name(n))
-        .collect();
-
-    names.sort_unstable();
-    names.dedup();
+    let names = sorted_names(guard.iter().flat_map(|op| names_of_kind(op, kind)));
 
     if names.is_empty() {
         return Err(ErrorResponse::NotFound {
             field: "Operator names".to_string(),
-            received: "".to_string(),
+            received: kind.map_or(PRIMARY_NAME_KEY, |k| k.label()).to_string(),
             expected: "at least one operator name".to_string(),
         });
     }
 
     Ok(names)
 }
+
+pub fn retrieve_network_names_by_type(
+    app_state: &AppState,
+) -> Result<BTreeMap<&'static str, Vec<String>>, ErrorResponse> {
+    let guard = app_state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+
+    let kinds = std::iter::once(None).chain(NameKind::ALL.into_iter().map(Some));
+    Ok(kinds
+        .map(|kind| {
+            let names = sorted_names(guard.iter().flat_map(|op| names_of_kind(op, kind)));
+            (kind.map_or(PRIMARY_NAME_KEY, |k| k.label()), names)
+        })
+        .collect())
+}
@@ -0,0 +1,219 @@
+use crate::{
+    app_state::model::AppState,
+    utils::{
+        error_responses::ErrorResponse,
+        models::{
+            AgreementQuery, AgreementStatus, CreateRoamingAgreement, Operator,
+            OperatorValidationError, RoamingAgreement,
+        },
+    },
+};
+use std::sync::Arc;
+
+fn agreement_not_found(id: u64) -> ErrorResponse {
+    ErrorResponse::NotFound {
+        field: "id".to_string(),
+        received: id.to_string(),
+        expected: "an existing roaming agreement id".to_string(),
+    }
+}
+
+fn invalid_agreement(field: &str, message: String, received: Option<String>) -> ErrorResponse {
+    OperatorValidationError::FieldValidationError {
+        field: field.to_string(),
+        message,
+        received,
+    }
+    .into()
+}
+
+fn resolve_tadig(
+    operators: &[Arc<Operator>],
+    field: &str,
+    tadig: &str,
+) -> Result<(u64, String), ErrorResponse> {
+    let tadig = tadig.trim();
+    operators
+        .iter()
+        .find_map(|op| {
+            op.tadig
+                .iter()
+                .flatten()
+                .find(|code| code.eq_ignore_ascii_case(tadig))
+                .map(|code| (op.id, code.clone()))
+        })
+        .ok_or_else(|| ErrorResponse::NotFound {
+            field: field.to_string(),
+            received: tadig.to_string(),
+            expected: "an existing TADIG code".to_string(),
+        })
+}
+
+fn build_agreement(
+    state: &AppState,
+    id: u64,
+    input: CreateRoamingAgreement,
+) -> Result<RoamingAgreement, ErrorResponse> {
+    let (home_id, home_tadig, partner_id, partner_tadig) = {
+        let operators = state
+            .operators
+            .read()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        let (home_id, home_tadig) = resolve_tadig(&operators, "home_tadig", &input.home_tadig)?;
+        let (partner_id, partner_tadig) =
+            resolve_tadig(&operators, "partner_tadig", &input.partner_tadig)?;
+        (home_id, home_tadig, partner_id, partner_tadig)
+    };
+    if home_id == partner_id {
+        return Err(invalid_agreement(
+            "partner_tadig",
+            "An agreement must link two different operators".to_string(),
+            Some(partner_tadig),
+        ));
+    }
+    if input.services.is_empty() {
+        return Err(invalid_agreement(
+            "services",
+            "services must list at least one of data or voice".to_string(),
+            None,
+        ));
+    }
+    if let Some(until) = input.valid_until
+        && until < input.valid_from
+    {
+        return Err(invalid_agreement(
+            "valid_until",
+            "valid_until must not be earlier than valid_from".to_string(),
+            Some(until.to_string()),
+        ));
+    }
+
+    let mut services = input.services;
+    services.dedup();
+    Ok(RoamingAgreement {
+        id,
+        home_tadig,
+        partner_tadig,
+        technology: input.technology,
+        services,
+        status: input.status,
+        direction: input.direction,
+        valid_from: input.valid_from,
+        valid_until: input.valid_until,
+    })
+}
+
+fn ensure_not_duplicated(
+    agreements: &[RoamingAgreement],
+    candidate: &RoamingAgreement,
+) -> Result<(), ErrorResponse> {
+    if candidate.status == AgreementStatus::Terminated {
+        return Ok(());
+    }
+    let clash = agreements.iter().any(|existing| {
+        existing.id != candidate.id
+            && existing.status != AgreementStatus::Terminated
+            && existing.technology == candidate.technology
+            && existing.links(&candidate.home_tadig, &candidate.partner_tadig)
+    });
+    if clash {
+        return Err(invalid_agreement(
+            "partner_tadig",
+            format!(
+                "A {} roaming agreement between {} and {} already exists",
+                candidate.technology.label(),
+                candidate.home_tadig,
+                candidate.partner_tadig
+            ),
+            Some(candidate.partner_tadig.clone()),
+        ));
+    }
+    Ok(())
+}
+
+pub fn list_agreements(
+    state: &AppState,
+    query: &AgreementQuery,
+) -> Result<Vec<RoamingAgreement>, ErrorResponse> {
+    let agreements = state
+        .agreements
+        .entries
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    Ok(agreements
+        .iter()
+        .filter(|a| {
+            query
+                .tadig
+                .as_deref()
+                .is_none_or(|tadig| a.involves(tadig.trim()))
+        })
+        .filter(|a| query.status.is_none_or(|status| a.status == status))
+        .cloned()
+        .collect())
+}
+
+pub fn get_agreement(state: &AppState, id: u64) -> Result<RoamingAgreement, ErrorResponse> {
+    state
+        .agreements
+        .entries
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?
+        .iter()
+        .find(|a| a.id == id)
+        .cloned()
+        .ok_or_else(|| agreement_not_found(id))
+}
+
+pub async fn create_agreement(
+    state: &AppState,
+    input: CreateRoamingAgreement,
+) -> Result<RoamingAgreement, ErrorResponse> {
+    let candidate = build_agreement(state, 0, input)?;
+    state
+        .agreements
+        .commit(|agreements| {
+            let mut created = candidate;
+            created.id = agreements.iter().map(|a| a.id).max().unwrap_or(0) + 1;
+            ensure_not_duplicated(agreements, &created)?;
+            agreements.push(created.clone());
+            Ok(created)
+        })
+        .await
+}
+
+pub async fn replace_agreement(
+    state: &AppState,
+    id: u64,
+    input: CreateRoamingAgreement,
+) -> Result<RoamingAgreement, ErrorResponse> {
+    let replacement = build_agreement(state, id, input)?;
+    state
+        .agreements
+        .commit(|agreements| {
+            ensure_not_duplicated(agreements, &replacement)?;
+            let slot = agreements
+                .iter_mut()
+                .find(|a| a.id == id)
+                .ok_or_else(|| agreement_not_found(id))?;
+            *slot = replacement.clone();
+            Ok(replacement)
+        })
+        .await
+}
+
+pub async fn delete_agreement(
+    state: &AppState,
+    id: u64,
+) -> Result<RoamingAgreement, ErrorResponse> {
+    state
+        .agreements
+        .commit(|agreements| {
+            let position = agreements
+                .iter()
+                .position(|a| a.id == id)
+                .ok_or_else(|| agreement_not_found(id))?;
+            Ok(agreements.remove(position))
+        })
+        .await
+}
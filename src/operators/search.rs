@@ -0,0 +1,158 @@
+use crate::{
+    app_state::{
+        model::AppState,
+        search_index::{SearchIndex, edit_distance, search_tokens},
+    },
+    utils::{
+        error_responses::ErrorResponse,
+        models::{
+            Operator, OperatorValidationError, SearchField, SearchMatch, SearchMatchKind,
+            SearchResponse, SearchResult,
+        },
+    },
+};
+use std::{collections::HashMap, sync::Arc};
+
+pub const DEFAULT_SEARCH_LIMIT: usize = 20;
+pub const MAX_SEARCH_LIMIT: usize = 100;
+
+fn allowed_edits(token: &str) -> usize {
+    match token.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+fn field_weight(field: SearchField) -> f64 {
+    match field {
+        SearchField::Name | SearchField::Tadig => 3.0,
+        SearchField::Country => 1.5,
+        SearchField::Realm => 1.0,
+    }
+}
+
+fn match_quality(query: &str, token: &str) -> Option<(SearchMatchKind, usize, f64)> {
+    if query == token {
+        return Some((SearchMatchKind::Exact, 0, 1.0));
+    }
+    if query.chars().count() >= 3 && token.starts_with(query) {
+        return Some((SearchMatchKind::Prefix, 0, 0.75));
+    }
+    edit_distance(query, token, allowed_edits(query)).map(|distance| {
+        (
+            SearchMatchKind::Fuzzy,
+            distance,
+            0.8 - 0.2 * distance as f64,
+        )
+    })
+}
+
+fn validate_search_limit(limit: Option<usize>) -> Result<usize, ErrorResponse> {
+    let limit = limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+    if (1..=MAX_SEARCH_LIMIT).contains(&limit) {
+        return Ok(limit);
+    }
+    Err(OperatorValidationError::FieldValidationError {
+        field: "limit".to_string(),
+        message: format!("limit must be between 1 and {}", MAX_SEARCH_LIMIT),
+        received: Some(limit.to_string()),
+    }
+    .into())
+}
+
+pub fn search_in(
+    operators: &[Arc<Operator>],
+    index: &SearchIndex,
+    query: &str,
+    limit: usize,
+) -> Result<SearchResponse, ErrorResponse> {
+    let query_tokens = search_tokens(query);
+    if query_tokens.is_empty() {
+        return Err(OperatorValidationError::FieldValidationError {
+            field: "q".to_string(),
+            message: "q must contain at least one letter or digit".to_string(),
+            received: Some(query.to_string()),
+        }
+        .into());
+    }
+
+    let mut best: HashMap<(usize, usize), (f64, SearchMatch)> = HashMap::new();
+    for (position, query_token) in query_tokens.iter().enumerate() {
+        for (token, hits) in index.tokens() {
+            let Some((kind, distance, quality)) = match_quality(query_token, token) else {
+                continue;
+            };
+            for hit in hits {
+                let score = quality * field_weight(hit.field);
+                let key = (hit.operator_index, position);
+                if best.get(&key).is_some_and(|(current, _)| *current >= score) {
+                    continue;
+                }
+                let found = SearchMatch {
+                    query: query_token.clone(),
+                    token: token.to_string(),
+                    field: hit.field,
+                    kind,
+                    distance,
+                };
+                best.insert(key, (score, found));
+            }
+        }
+    }
+
+    let mut by_operator: HashMap<usize, (f64, Vec<SearchMatch>)> = HashMap::new();
+    for ((operator_index, _), (score, found)) in best {
+        let entry = by_operator.entry(operator_index).or_default();
+        entry.0 += score;
+        entry.1.push(found);
+    }
+
+    let mut results: Vec<SearchResult> = by_operator
+        .into_iter()
+        .filter_map(|(operator_index, (score, mut matches))| {
+            let operator = operators.get(operator_index)?.clone();
+            matches.sort_by(|a, b| {
+                let position = |m: &SearchMatch| query_tokens.iter().position(|q| *q == m.query);
+                position(a).cmp(&position(b))
+            });
+            let coverage = matches.len() as f64 / query_tokens.len() as f64;
+            Some(SearchResult {
+                score: (score * coverage * 1000.0).round() / 1000.0,
+                matches,
+                operator,
+            })
+        })
+        .collect();
+    results.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.operator.name.cmp(&b.operator.name))
+            .then_with(|| a.operator.id.cmp(&b.operator.id))
+    });
+
+    let total = results.len();
+    results.truncate(limit);
+    Ok(SearchResponse {
+        query: query.to_string(),
+        total,
+        results,
+    })
+}
+
+pub fn search_operators(
+    state: &AppState,
+    query: &str,
+    limit: Option<usize>,
+) -> Result<SearchResponse, ErrorResponse> {
+    let limit = validate_search_limit(limit)?;
+    let operators = state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    let index = state
+        .search_index
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    search_in(&operators, &index, query, limit)
+}
@@ -0,0 +1,281 @@
+use crate::{
+    app_state::model::AppState,
+    operators::crud_operations::read::lookup_operator_by_query,
+    utils::{
+        countries::find_country_by_alpha3,
+        error_responses::ErrorResponse,
+        models::{
+            MatchMode, Operator, OperatorValidationError, QueryType, SetSteeringPreference,
+            SteeredPartner, SteeringPreference, SteeringQuery, SteeringResult,
+        },
+    },
+};
+use chrono::Utc;
+use std::{collections::HashSet, sync::Arc};
+
+fn find_by_tadig<'a>(operators: &'a [Arc<Operator>], tadig: &str) -> Option<&'a Arc<Operator>> {
+    operators.iter().find(|op| {
+        op.tadig
+            .iter()
+            .flatten()
+            .any(|t| t.eq_ignore_ascii_case(tadig))
+    })
+}
+
+fn canonical_tadig(op: &Operator, tadig: &str) -> String {
+    op.tadig
+        .iter()
+        .flatten()
+        .find(|t| t.eq_ignore_ascii_case(tadig))
+        .cloned()
+        .unwrap_or_else(|| tadig.to_string())
+}
+
+fn resolve_visited_iso3(iso3: &str) -> Result<String, ErrorResponse> {
+    find_country_by_alpha3(iso3)
+        .map(|country| country.alpha3.clone())
+        .ok_or_else(|| ErrorResponse::NotFound {
+            field: "iso3".to_string(),
+            received: iso3.to_string(),
+            expected: "an ISO3 code from the ISO 3166-1 table".to_string(),
+        })
+}
+
+fn preference_not_found(home: &str, iso3: &str) -> ErrorResponse {
+    ErrorResponse::NotFound {
+        field: "visited_iso3".to_string(),
+        received: iso3.to_string(),
+        expected: format!("a steering preference list for home network {}", home),
+    }
+}
+
+fn home_not_found(home: &str) -> ErrorResponse {
+    ErrorResponse::NotFound {
+        field: "home".to_string(),
+        received: home.to_string(),
+        expected: "an existing TADIG code".to_string(),
+    }
+}
+
+fn home_aliases(state: &AppState, home: &str) -> Result<Vec<String>, ErrorResponse> {
+    let operators = state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    Ok(match find_by_tadig(&operators, home) {
+        Some(op) => op.tadig.clone().unwrap_or_default(),
+        None => vec![home.to_string()],
+    })
+}
+
+fn is_home_of(preference: &SteeringPreference, home_aliases: &[String]) -> bool {
+    home_aliases
+        .iter()
+        .any(|alias| alias.eq_ignore_ascii_case(&preference.home_tadig))
+}
+
+fn is_same_preference(
+    preference: &SteeringPreference,
+    home_aliases: &[String],
+    iso3: &str,
+) -> bool {
+    is_home_of(preference, home_aliases) && preference.visited_iso3 == iso3
+}
+
+fn find_preference(
+    state: &AppState,
+    home: &str,
+    home_aliases: &[String],
+    visited_iso3: &str,
+) -> Result<SteeringPreference, ErrorResponse> {
+    state
+        .steering
+        .entries
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?
+        .iter()
+        .find(|p| is_same_preference(p, home_aliases, visited_iso3))
+        .cloned()
+        .ok_or_else(|| preference_not_found(home, visited_iso3))
+}
+
+pub async fn set_steering_preference(
+    state: &AppState,
+    home: &str,
+    iso3: &str,
+    input: SetSteeringPreference,
+) -> Result<SteeringPreference, ErrorResponse> {
+    let visited_iso3 = resolve_visited_iso3(iso3)?;
+    if input.partners.is_empty() {
+        return Err(OperatorValidationError::FieldValidationError {
+            field: "partners".to_string(),
+            message: "partners must list at least one TADIG".to_string(),
+            received: None,
+        }
+        .into());
+    }
+
+    let (preference, aliases) = {
+        let operators = state
+            .operators
+            .read()
+            .map_err(|_| ErrorResponse::InternalError)?;
+        let home_operator =
+            find_by_tadig(&operators, home.trim()).ok_or_else(|| home_not_found(home))?;
+
+        let mut seen = HashSet::new();
+        let mut partners = Vec::with_capacity(input.partners.len());
+        for mut partner in input.partners {
+            let operator = find_by_tadig(&operators, partner.tadig.trim()).ok_or_else(|| {
+                ErrorResponse::NotFound {
+                    field: "partners".to_string(),
+                    received: partner.tadig.clone(),
+                    expected: "an existing TADIG code".to_string(),
+                }
+            })?;
+            if !operator.iso3.eq_ignore_ascii_case(&visited_iso3) {
+                return Err(OperatorValidationError::FieldValidationError {
+                    field: "partners".to_string(),
+                    message: format!(
+                        "Partner {} operates in {}, not in {}",
+                        partner.tadig, operator.iso3, visited_iso3
+                    ),
+                    received: Some(partner.tadig),
+                }
+                .into());
+            }
+            if !seen.insert(operator.id) {
+                return Err(OperatorValidationError::FieldValidationError {
+                    field: "partners".to_string(),
+                    message: format!("Partner {} is listed more than once", partner.tadig),
+                    received: Some(partner.tadig),
+                }
+                .into());
+            }
+            partner.tadig = canonical_tadig(operator, partner.tadig.trim());
+            partners.push(partner);
+        }
+
+        let preference = SteeringPreference {
+            home_tadig: canonical_tadig(home_operator, home.trim()),
+            visited_iso3,
+            partners,
+            updated_at: Utc::now(),
+        };
+        (preference, home_operator.tadig.clone().unwrap_or_default())
+    };
+
+    state
+        .steering
+        .commit(|preferences| {
+            preferences.retain(|p| !is_same_preference(p, &aliases, &preference.visited_iso3));
+            preferences.push(preference.clone());
+            Ok(preference)
+        })
+        .await
+}
+
+pub fn list_steering_preferences(
+    state: &AppState,
+    query: &SteeringQuery,
+) -> Result<Vec<SteeringPreference>, ErrorResponse> {
+    let home_aliases = query
+        .home
+        .as_deref()
+        .map(|home| home_aliases(state, home.trim()))
+        .transpose()?;
+    let preferences = state
+        .steering
+        .entries
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    Ok(preferences
+        .iter()
+        .filter(|p| {
+            home_aliases
+                .as_deref()
+                .is_none_or(|aliases| is_home_of(p, aliases))
+        })
+        .filter(|p| {
+            query
+                .iso3
+                .as_deref()
+                .is_none_or(|iso3| p.visited_iso3.eq_ignore_ascii_case(iso3.trim()))
+        })
+        .cloned()
+        .collect())
+}
+
+pub fn get_steering_preference(
+    state: &AppState,
+    home: &str,
+    iso3: &str,
+) -> Result<SteeringPreference, ErrorResponse> {
+    let visited_iso3 = resolve_visited_iso3(iso3)?;
+    let aliases = home_aliases(state, home.trim())?;
+    find_preference(state, home, &aliases, &visited_iso3)
+}
+
+pub async fn delete_steering_preference(
+    state: &AppState,
+    home: &str,
+    iso3: &str,
+) -> Result<SteeringPreference, ErrorResponse> {
+    let visited_iso3 = resolve_visited_iso3(iso3)?;
+    let aliases = home_aliases(state, home.trim())?;
+    state
+        .steering
+        .commit(|preferences| {
+            let position = preferences
+                .iter()
+                .position(|p| is_same_preference(p, &aliases, &visited_iso3))
+                .ok_or_else(|| preference_not_found(home, &visited_iso3))?;
+            Ok(preferences.remove(position))
+        })
+        .await
+}
+
+pub fn steer_subscriber(
+    state: &AppState,
+    home: &str,
+    imsi: &str,
+) -> Result<SteeringResult, ErrorResponse> {
+    let home = home.trim();
+    let visited = lookup_operator_by_query(state, imsi, QueryType::Imsi, MatchMode::Strict)?
+        .ok_or_else(|| ErrorResponse::NotFound {
+            field: "imsi".to_string(),
+            received: imsi.to_string(),
+            expected: "an IMSI whose prefix belongs to a known operator".to_string(),
+        })?;
+    let visited_iso3 = visited.operator.iso3.to_ascii_uppercase();
+
+    let operators = state
+        .operators
+        .read()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    let home_operator = find_by_tadig(&operators, home).ok_or_else(|| home_not_found(home))?;
+    let aliases = home_operator.tadig.clone().unwrap_or_default();
+    let preference = find_preference(state, home, &aliases, &visited_iso3)?;
+
+    let partners = preference
+        .partners
+        .iter()
+        .filter_map(|partner| {
+            find_by_tadig(&operators, &partner.tadig).map(|op| (partner.weight, op.clone()))
+        })
+        .enumerate()
+        .map(|(position, (weight, operator))| SteeredPartner {
+            rank: position + 1,
+            weight,
+            operator,
+        })
+        .collect();
+
+    Ok(SteeringResult {
+        home_tadig: canonical_tadig(home_operator, home),
+        visited_iso3,
+        visited_network: visited.operator,
+        matched_prefix: visited.matched_prefix,
+        partners,
+    })
+}
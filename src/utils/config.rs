@@ -1,9 +1,100 @@
 use actix_web::web;
+use serde::{Deserialize, Serialize};
 use serde_yaml;
 use std::{env, fs};
 use utoipa::openapi::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageBackend {
+    Json,
+    Sqlite,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityMode {
+    Strict,
+    Lenient,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeBasis {
+    E212,
+    E164,
+    Weighted,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SizeTier {
+    pub name: String,
+    pub max_score: Option<f64>,
+}
+
+impl SizeTier {
+    pub fn new(name: &str, max_score: Option<f64>) -> Self {
+        SizeTier {
+            name: name.to_string(),
+            max_score,
+        }
+    }
+}
+
+pub fn default_size_tiers() -> Vec<SizeTier> {
+    vec![
+        SizeTier::new("small", Some(3.0)),
+        SizeTier::new("medium", Some(7.0)),
+        SizeTier::new("large", None),
+    ]
+}
+
+pub fn parse_size_tiers(raw: &str) -> Result<Vec<SizeTier>, String> {
+    let mut tiers: Vec<SizeTier> = Vec::new();
+    for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        if tiers.last().is_some_and(|t| t.max_score.is_none()) {
+            return Err(format!(
+                "Tier '{}' follows an unbounded tier; only the last tier may omit its maximum",
+                entry
+            ));
+        }
+        let (name, max_score) = match entry.split_once(':') {
+            Some((name, max)) => {
+                let max: f64 = max
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("Tier '{}' has a non-numeric maximum", entry))?;
+                (name.trim(), Some(max))
+            }
+            None => (entry, None),
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(format!(
+                "Tier name '{}' must be letters, digits or underscores",
+                name
+            ));
+        }
+        if tiers.iter().any(|t| t.name.eq_ignore_ascii_case(name)) {
+            return Err(format!("Tier name '{}' is listed more than once", name));
+        }
+        if let (Some(max), Some(previous)) = (max_score, tiers.last().and_then(|t| t.max_score))
+            && max <= previous
+        {
+            return Err(format!(
+                "Tier '{}' maximum {} must be greater than {}",
+                name, max, previous
+            ));
+        }
+        tiers.push(SizeTier::new(&name.to_ascii_lowercase(), max_score));
+    }
+    match tiers.last() {
+        None => Err("At least one tier is required".to_string()),
+        Some(last) if last.max_score.is_some() => {
+            Err("The last tier must omit its maximum so every operator has a tier".to_string())
+        }
+        Some(_) => Ok(tiers),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub save_interval_minutes: u64,
@@ -12,6 +103,19 @@ pub struct Config {
     pub workers: usize,
     pub operator_mappings_file_path: String,
     pub country_borders_file_path: String,
+    pub audit_log_file_path: String,
+    pub operator_history_file_path: String,
+    pub roaming_agreements_file_path: String,
+    pub steering_preferences_file_path: String,
+    pub storage_backend: StorageBackend,
+    pub sqlite_database_path: String,
+    pub reload_watch_interval_seconds: u64,
+    pub integrity_mode: IntegrityMode,
+    pub e164_validation_mode: IntegrityMode,
+    pub size_tiers: Vec<SizeTier>,
+    pub size_basis: SizeBasis,
+    pub size_weight_e212: f64,
+    pub size_weight_e164: f64,
 }
 
 impl Default for Config {
@@ -23,6 +127,19 @@ impl Default for Config {
             workers: 4,
             operator_mappings_file_path: "./resources/operator_mappings.json".to_string(),
             country_borders_file_path: "./resources/country_borders.csv".to_string(),
+            audit_log_file_path: "./resources/audit_log.jsonl".to_string(),
+            operator_history_file_path: "./resources/operator_history.jsonl".to_string(),
+            roaming_agreements_file_path: "./resources/roaming_agreements.json".to_string(),
+            steering_preferences_file_path: "./resources/steering_preferences.json".to_string(),
+            storage_backend: StorageBackend::Json,
+            sqlite_database_path: "./resources/operator_mappings.db".to_string(),
+            reload_watch_interval_seconds: 0,
+            integrity_mode: IntegrityMode::Lenient,
+            e164_validation_mode: IntegrityMode::Lenient,
+            size_tiers: default_size_tiers(),
+            size_basis: SizeBasis::E212,
+            size_weight_e212: 1.0,
+            size_weight_e164: 1.0,
         }
     }
 }
@@ -49,13 +166,52 @@ impl Config {
         }
     }
 
+    fn parse_env_backend(key: &str, default: StorageBackend) -> StorageBackend {
+        match env::var(key).map(|s| s.trim().to_ascii_lowercase()) {
+            Ok(s) if s == "json" => StorageBackend::Json,
+            Ok(s) if s == "sqlite" => StorageBackend::Sqlite,
+            _ => default,
+        }
+    }
+
+    fn parse_env_integrity_mode(key: &str, default: IntegrityMode) -> IntegrityMode {
+        match env::var(key).map(|s| s.trim().to_ascii_lowercase()) {
+            Ok(s) if s == "strict" => IntegrityMode::Strict,
+            Ok(s) if s == "lenient" => IntegrityMode::Lenient,
+            _ => default,
+        }
+    }
+
+    fn parse_env_f64(key: &str, default: f64) -> f64 {
+        match env::var(key) {
+            Ok(s) => s.parse().unwrap_or(default),
+            Err(_) => default,
+        }
+    }
+
+    fn parse_env_size_basis(key: &str, default: SizeBasis) -> SizeBasis {
+        match env::var(key).map(|s| s.trim().to_ascii_lowercase()) {
+            Ok(s) if s == "e212" => SizeBasis::E212,
+            Ok(s) if s == "e164" => SizeBasis::E164,
+            Ok(s) if s == "weighted" => SizeBasis::Weighted,
+            _ => default,
+        }
+    }
+
+    fn parse_env_size_tiers(key: &str, default: Vec<SizeTier>) -> Result<Vec<SizeTier>, String> {
+        match env::var(key) {
+            Ok(s) => parse_size_tiers(&s).map_err(|e| format!("{} is invalid: {}", key, e)),
+            Err(_) => Ok(default),
+        }
+    }
+
     fn parse_env_string(key: &str, default: &str) -> String {
         env::var(key).unwrap_or_else(|_| default.to_string())
     }
 
-    pub fn from_env() -> Self {
+    pub fn from_env() -> Result<Self, String> {
         let d = Config::default();
-        Config {
+        Ok(Config {
             save_interval_minutes: Self::parse_env_u64(
                 "SAVE_INTERVAL_MINUTES",
                 d.save_interval_minutes,
@@ -71,11 +227,45 @@ impl Config {
                 "COUNTRY_BORDERS_CSV_FILE_PATH",
                 &d.country_borders_file_path,
             ),
-        }
+            audit_log_file_path: Self::parse_env_string(
+                "AUDIT_LOG_FILE_PATH",
+                &d.audit_log_file_path,
+            ),
+            operator_history_file_path: Self::parse_env_string(
+                "OPERATOR_HISTORY_FILE_PATH",
+                &d.operator_history_file_path,
+            ),
+            roaming_agreements_file_path: Self::parse_env_string(
+                "ROAMING_AGREEMENTS_FILE_PATH",
+                &d.roaming_agreements_file_path,
+            ),
+            steering_preferences_file_path: Self::parse_env_string(
+                "STEERING_PREFERENCES_FILE_PATH",
+                &d.steering_preferences_file_path,
+            ),
+            storage_backend: Self::parse_env_backend("STORAGE_BACKEND", d.storage_backend),
+            sqlite_database_path: Self::parse_env_string(
+                "SQLITE_DATABASE_PATH",
+                &d.sqlite_database_path,
+            ),
+            reload_watch_interval_seconds: Self::parse_env_u64(
+                "RELOAD_WATCH_INTERVAL_SECONDS",
+                d.reload_watch_interval_seconds,
+            ),
+            integrity_mode: Self::parse_env_integrity_mode("INTEGRITY_MODE", d.integrity_mode),
+            e164_validation_mode: Self::parse_env_integrity_mode(
+                "E164_VALIDATION_MODE",
+                d.e164_validation_mode,
+            ),
+            size_tiers: Self::parse_env_size_tiers("OPERATOR_SIZE_TIERS", d.size_tiers)?,
+            size_basis: Self::parse_env_size_basis("OPERATOR_SIZE_BASIS", d.size_basis),
+            size_weight_e212: Self::parse_env_f64("OPERATOR_SIZE_WEIGHT_E212", d.size_weight_e212),
+            size_weight_e164: Self::parse_env_f64("OPERATOR_SIZE_WEIGHT_E164", d.size_weight_e164),
+        })
     }
 }
 
-pub fn load() -> Config {
+pub fn load() -> Result<Config, String> {
     Config::from_env()
 }
 
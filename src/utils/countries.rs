@@ -0,0 +1,108 @@
+use crate::utils::{
+    error_responses::ErrorResponse,
+    models::{CountryRecord, OperatorValidationError},
+    validations::validate_non_empty,
+};
+use csv::StringRecord;
+use once_cell::sync::Lazy as SyncLazy;
+use std::collections::HashMap;
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+const ISO3166_CSV: &str = include_str!("../../resources/iso3166.csv");
+
+pub static COUNTRIES: SyncLazy<Vec<CountryRecord>> = SyncLazy::new(|| {
+    parse_country_table(ISO3166_CSV).expect("bundled ISO 3166-1 table must parse")
+});
+
+pub fn parse_country_table(raw_csv: &str) -> Result<Vec<CountryRecord>, String> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(raw_csv.as_bytes());
+
+    let mut countries = Vec::new();
+    for result in rdr.records() {
+        let record: StringRecord = result.map_err(|e| format!("CSV parse error: {}", e))?;
+        if record.len() < 5 {
+            return Err(format!(
+                "Country row has {} columns, expected 5",
+                record.len()
+            ));
+        }
+        countries.push(CountryRecord {
+            alpha2: record[0].trim().to_string(),
+            alpha3: record[1].trim().to_string(),
+            numeric: record[2].trim().to_string(),
+            name: record[3].trim().to_string(),
+            aliases: record[4]
+                .split(';')
+                .map(str::trim)
+                .filter(|alias| !alias.is_empty())
+                .map(str::to_string)
+                .collect(),
+        });
+    }
+    Ok(countries)
+}
+
+pub fn normalise_country_name(value: &str) -> String {
+    let folded: String = value
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    folded
+        .to_lowercase()
+        .split_whitespace()
+        .filter(|word| *word != "the")
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+static COUNTRY_INDEX: SyncLazy<HashMap<String, usize>> = SyncLazy::new(|| {
+    let mut index = HashMap::new();
+    for (position, country) in COUNTRIES.iter().enumerate() {
+        for name in std::iter::once(&country.name).chain(&country.aliases) {
+            index
+                .entry(normalise_country_name(name))
+                .or_insert(position);
+        }
+    }
+    index
+});
+
+pub fn find_country_by_alpha2(alpha2: &str) -> Option<&'static CountryRecord> {
+    let alpha2 = alpha2.trim();
+    COUNTRIES
+        .iter()
+        .find(|c| c.alpha2.eq_ignore_ascii_case(alpha2))
+}
+
+pub fn find_country_by_alpha3(alpha3: &str) -> Option<&'static CountryRecord> {
+    let alpha3 = alpha3.trim();
+    COUNTRIES
+        .iter()
+        .find(|c| c.alpha3.eq_ignore_ascii_case(alpha3))
+}
+
+pub fn find_country(input: &str) -> Option<&'static CountryRecord> {
+    let key = normalise_country_name(input);
+    COUNTRY_INDEX
+        .get(&key)
+        .map(|&position| &COUNTRIES[position])
+        .or_else(|| match key.len() {
+            2 => find_country_by_alpha2(&key),
+            3 => find_country_by_alpha3(&key),
+            _ => None,
+        })
+}
+
+pub fn resolve_country(field: &str, input: &str) -> Result<&'static CountryRecord, ErrorResponse> {
+    let name = validate_non_empty(field, input).map_err(ErrorResponse::Validation)?;
+    find_country(name).ok_or_else(|| {
+        ErrorResponse::Validation(OperatorValidationError::InvalidCountry {
+            field: field.to_string(),
+            received: name.to_string(),
+            expected: "a country name, alias or code from the ISO 3166-1 table".to_string(),
+        })
+    })
+}
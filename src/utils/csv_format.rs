@@ -0,0 +1,171 @@
+use crate::utils::{
+    error_responses::ErrorResponse,
+    models::{CreateOperator, NameKind, Operator, OperatorName, OperatorValidationError},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MULTI_VALUE_SEPARATOR: char = ';';
+const ESCAPE: char = '\\';
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct CsvOperatorRow {
+    country: String,
+    #[serde(default)]
+    iso2: String,
+    #[serde(default)]
+    iso3: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    e212: String,
+    #[serde(default)]
+    e164: String,
+    #[serde(default)]
+    realm: String,
+    #[serde(default)]
+    tadig: String,
+    #[serde(default)]
+    names: String,
+}
+
+fn escape_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c == ESCAPE || c == MULTI_VALUE_SEPARATOR {
+            escaped.push(ESCAPE);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn split_escaped(cell: &str) -> Vec<String> {
+    let mut values = vec![String::new()];
+    let mut chars = cell.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            ESCAPE => values
+                .last_mut()
+                .unwrap()
+                .push(chars.next().unwrap_or(ESCAPE)),
+            MULTI_VALUE_SEPARATOR => values.push(String::new()),
+            _ => values.last_mut().unwrap().push(c),
+        }
+    }
+    values
+}
+
+fn join_values(values: &Option<Vec<String>>) -> String {
+    values
+        .iter()
+        .flatten()
+        .map(|value| escape_value(value))
+        .collect::<Vec<_>>()
+        .join(&MULTI_VALUE_SEPARATOR.to_string())
+}
+
+fn split_values(cell: &str) -> Option<Vec<String>> {
+    let values: Vec<String> = split_escaped(cell)
+        .iter()
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .collect();
+    if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+fn join_names(names: &Option<Vec<OperatorName>>) -> String {
+    names
+        .iter()
+        .flatten()
+        .map(|name| format!("{}:{}", name.kind.label(), escape_value(&name.value)))
+        .collect::<Vec<_>>()
+        .join(&MULTI_VALUE_SEPARATOR.to_string())
+}
+
+fn split_names(cell: &str) -> Result<Option<Vec<OperatorName>>, String> {
+    let Some(entries) = split_values(cell) else {
+        return Ok(None);
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            let (kind, value) = entry
+                .split_once(':')
+                .ok_or_else(|| format!("name '{}' must be written as kind:value", entry))?;
+            let kind = NameKind::parse(kind).ok_or_else(|| {
+                format!(
+                    "name kind '{}' must be one of legal, brand, short or historic",
+                    kind
+                )
+            })?;
+            Ok(OperatorName {
+                kind,
+                value: value.trim().to_string(),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()
+        .map(Some)
+}
+
+fn csv_error(message: String) -> ErrorResponse {
+    OperatorValidationError::FieldValidationError {
+        field: "csv".to_string(),
+        message,
+        received: None,
+    }
+    .into()
+}
+
+pub fn operators_to_csv(operators: &[&Operator]) -> Result<String, ErrorResponse> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for op in operators {
+        writer
+            .serialize(CsvOperatorRow {
+                country: op.country.clone(),
+                iso2: op.iso2.clone(),
+                iso3: op.iso3.clone(),
+                name: op.name.clone().unwrap_or_default(),
+                e212: join_values(&op.e212),
+                e164: join_values(&op.e164),
+                realm: join_values(&op.realm),
+                tadig: join_values(&op.tadig),
+                names: join_names(&op.names),
+            })
+            .map_err(|_| ErrorResponse::InternalError)?;
+    }
+    let bytes = writer
+        .into_inner()
+        .map_err(|_| ErrorResponse::InternalError)?;
+    String::from_utf8(bytes).map_err(|_| ErrorResponse::InternalError)
+}
+
+pub fn operators_from_csv(raw_csv: &str) -> Result<Vec<CreateOperator>, ErrorResponse> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_reader(raw_csv.as_bytes());
+
+    reader
+        .deserialize::<CsvOperatorRow>()
+        .enumerate()
+        .map(|(row_index, record)| {
+            let row = record.map_err(|e| csv_error(format!("CSV row {}: {}", row_index, e)))?;
+            let name = row.name.trim();
+            Ok(CreateOperator {
+                country: row.country,
+                e164: split_values(&row.e164),
+                e212: split_values(&row.e212),
+                name: (!name.is_empty()).then(|| name.to_string()),
+                names: split_names(&row.names)
+                    .map_err(|e| csv_error(format!("CSV row {}: {}", row_index, e)))?,
+                realm: split_values(&row.realm),
+                tadig: split_values(&row.tadig),
+            })
+        })
+        .collect()
+}
@@ -15,6 +15,7 @@ pub enum ErrorType {
     FieldValidationError,
     NotFound,
     BorderCountryNotFound,
+    PreconditionFailed,
     InternalError,
 }
 
@@ -31,6 +32,7 @@ impl Display for ErrorType {
             ErrorType::FieldValidationError => "FieldValidationError",
             ErrorType::NotFound => "NotFound",
             ErrorType::BorderCountryNotFound => "BorderCountryNotFound",
+            ErrorType::PreconditionFailed => "PreconditionFailed",
             ErrorType::InternalError => "InternalError",
         };
         write!(f, "{}", s)
@@ -157,6 +159,11 @@ pub enum ErrorResponse {
         received: String,
         expected: String,
     },
+    PreconditionFailed {
+        field: String,
+        received: String,
+        expected: String,
+    },
     InternalError,
 }
 
@@ -188,6 +195,18 @@ impl ErrorResponse {
                 expected: Some(expected),
                 received: Some(received),
             },
+            ErrorResponse::PreconditionFailed {
+                field,
+                received,
+                expected,
+            } => ErrorInfo {
+                status: StatusCode::PRECONDITION_FAILED,
+                error_type: ErrorType::PreconditionFailed,
+                message: "Operator was modified since it was last read".to_string(),
+                field,
+                expected: Some(expected),
+                received: Some(received),
+            },
             ErrorResponse::InternalError => ErrorInfo {
                 status: StatusCode::INTERNAL_SERVER_ERROR,
                 error_type: ErrorType::InternalError,
@@ -199,7 +218,7 @@ impl ErrorResponse {
         }
     }
 
-    fn into_http(self) -> HttpResponse {
+    pub fn into_status_and_body(self) -> (StatusCode, Value) {
         let info = self.into_info();
         let mut body = json!({
             "error_type": info.error_type.to_string(),
@@ -212,7 +231,12 @@ impl ErrorResponse {
         if let Some(rec) = info.received {
             body["received"] = Value::String(rec);
         }
-        HttpResponse::build(info.status).json(body)
+        (info.status, body)
+    }
+
+    fn into_http(self) -> HttpResponse {
+        let (status, body) = self.into_status_and_body();
+        HttpResponse::build(status).json(body)
     }
 }
 
@@ -224,6 +248,9 @@ impl Display for ErrorResponse {
             ErrorResponse::BorderCountryNotFound { field, .. } => {
                 write!(f, "Bordering countries for {} not found", field)
             }
+            ErrorResponse::PreconditionFailed { field, .. } => {
+                write!(f, "Precondition on {} failed", field)
+            }
             ErrorResponse::InternalError => write!(f, "Internal server error"),
         }
     }
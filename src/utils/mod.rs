@@ -1,5 +1,8 @@
 pub mod config;
+pub mod countries;
+pub mod csv_format;
 pub mod error_responses;
 pub mod models;
+pub mod numbering;
 pub mod routes;
 pub mod validations;
@@ -1,8 +1,15 @@
+use crate::utils::{config::SizeBasis, error_responses::ErrorResponse};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Operator {
+    #[serde(default)]
+    pub id: u64,
     pub country: String,
     #[serde(default)]
     pub e164: Option<Vec<String>>,
@@ -13,39 +20,190 @@ pub struct Operator {
     #[serde(default)]
     pub name: Option<String>,
     #[serde(default)]
+    pub names: Option<Vec<OperatorName>>,
+    #[serde(default)]
     pub realm: Option<Vec<String>>,
     #[serde(default)]
     pub tadig: Option<Vec<String>>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum NameKind {
+    Legal,
+    Brand,
+    Short,
+    Historic,
+}
+
+impl NameKind {
+    pub const ALL: [NameKind; 4] = [
+        NameKind::Legal,
+        NameKind::Brand,
+        NameKind::Short,
+        NameKind::Historic,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NameKind::Legal => "legal",
+            NameKind::Brand => "brand",
+            NameKind::Short => "short",
+            NameKind::Historic => "historic",
+        }
+    }
+
+    pub fn parse(label: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.label().eq_ignore_ascii_case(label.trim()))
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct NetworkNamesQuery {
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub by_type: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OperatorName {
+    pub kind: NameKind,
+    pub value: String,
+}
+
 impl Operator {
+    pub fn all_names(&self) -> impl Iterator<Item = &str> {
+        self.name
+            .iter()
+            .map(String::as_str)
+            .chain(self.names.iter().flatten().map(|n| n.value.as_str()))
+    }
+
     pub fn has_tadig(&self, key: &str) -> bool {
         self.tadig
             .as_ref()
             .is_some_and(|codes| codes.iter().any(|c| c == key))
     }
 
-    pub fn from_create(country: String, iso2: String, iso3: String, input: CreateOperator) -> Self {
+    pub fn primary_tadig(&self) -> &str {
+        self.tadig
+            .as_ref()
+            .and_then(|codes| codes.first())
+            .map_or("", String::as_str)
+    }
+
+    pub fn etag(&self) -> String {
+        let serialized = serde_json::to_vec(self).unwrap_or_default();
+        let hash = serialized
+            .iter()
+            .fold(0xcbf29ce484222325_u64, |hash, byte| {
+                (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+            });
+        format!("\"{:016x}\"", hash)
+    }
+
+    pub fn from_create(
+        id: u64,
+        country: String,
+        iso2: String,
+        iso3: String,
+        input: CreateOperator,
+    ) -> Self {
         let tadig_list = input.tadig.unwrap_or_default();
         Operator {
+            id,
             country,
             e164: input.e164,
             e212: input.e212,
             iso2,
             iso3,
             name: input.name,
+            names: input.names,
             realm: input.realm,
             tadig: Some(tadig_list),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum OperatorKey<'a> {
+    Tadig(&'a str),
+    Id(u64),
+}
+
+impl<'a> OperatorKey<'a> {
+    pub fn tadig_for<'b>(&self, operator: &'b Operator) -> &'b str
+    where
+        'a: 'b,
+    {
+        match self {
+            OperatorKey::Tadig(tadig) => tadig,
+            OperatorKey::Id(_) => operator.primary_tadig(),
+        }
+    }
+
+    pub fn matches(&self, operator: &Operator) -> bool {
+        match self {
+            OperatorKey::Tadig(tadig) => operator.has_tadig(tadig),
+            OperatorKey::Id(id) => operator.id == *id,
+        }
+    }
+
+    pub fn not_found(&self) -> ErrorResponse {
+        let (field, received, expected) = match self {
+            OperatorKey::Tadig(tadig) => ("tadig", tadig.to_string(), "an existing TADIG code"),
+            OperatorKey::Id(id) => ("id", id.to_string(), "an existing operator id"),
+        };
+        ErrorResponse::NotFound {
+            field: field.to_string(),
+            received,
+            expected: expected.to_string(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperatorKey<'a> {
+    fn from(tadig: &'a str) -> Self {
+        OperatorKey::Tadig(tadig)
+    }
+}
+
+impl<'a> From<&'a String> for OperatorKey<'a> {
+    fn from(tadig: &'a String) -> Self {
+        OperatorKey::Tadig(tadig)
+    }
+}
+
+impl From<u64> for OperatorKey<'_> {
+    fn from(id: u64) -> Self {
+        OperatorKey::Id(id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OperatorUpdate {
+    pub previous: Arc<Operator>,
+    pub updated: Arc<Operator>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RestoredOperator {
+    pub lineage: u64,
+    pub previous: Option<Arc<Operator>>,
+    pub restored: Arc<Operator>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CreateOperator {
     pub country: String,
     pub e164: Option<Vec<String>>,
     pub e212: Option<Vec<String>>,
     pub name: Option<String>,
+    #[serde(default)]
+    pub names: Option<Vec<OperatorName>>,
     pub realm: Option<Vec<String>>,
     pub tadig: Option<Vec<String>>,
 }
@@ -58,10 +216,303 @@ pub struct PatchOperator {
     pub e164: Option<Vec<String>>,
     pub e212: Option<Vec<String>>,
     pub name: Option<Option<String>>,
+    pub names: Option<Vec<OperatorName>>,
     pub realm: Option<Vec<String>>,
     pub tadig: Option<Vec<String>>,
 }
 
+#[derive(Deserialize, Debug, Default)]
+pub struct ImportQuery {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug)]
+pub struct ImportOutcome {
+    pub committed: bool,
+    pub rows: Vec<ImportRowOutcome>,
+    pub imported: Vec<Arc<Operator>>,
+}
+
+#[derive(Debug)]
+pub struct ImportRowOutcome {
+    pub tadig: Vec<String>,
+    pub result: Result<(), ErrorResponse>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ImportRowReport<'a> {
+    pub row: usize,
+    pub tadig: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ReloadSummary {
+    pub operators: usize,
+    pub countries_with_borders: usize,
+    pub backfilled_ids: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AgreementTechnology {
+    Gsm,
+    Lte,
+    #[serde(rename = "5g")]
+    FiveG,
+}
+
+impl AgreementTechnology {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AgreementTechnology::Gsm => "GSM",
+            AgreementTechnology::Lte => "LTE",
+            AgreementTechnology::FiveG => "5G",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AgreementService {
+    Data,
+    Voice,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AgreementStatus {
+    Draft,
+    Active,
+    Suspended,
+    Terminated,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AgreementDirection {
+    #[default]
+    Bilateral,
+    Outbound,
+    Inbound,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RoamingAgreement {
+    pub id: u64,
+    pub home_tadig: String,
+    pub partner_tadig: String,
+    pub technology: AgreementTechnology,
+    pub services: Vec<AgreementService>,
+    pub status: AgreementStatus,
+    pub direction: AgreementDirection,
+    pub valid_from: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<NaiveDate>,
+}
+
+impl RoamingAgreement {
+    pub fn links(&self, a: &str, b: &str) -> bool {
+        (self.home_tadig.eq_ignore_ascii_case(a) && self.partner_tadig.eq_ignore_ascii_case(b))
+            || (self.home_tadig.eq_ignore_ascii_case(b)
+                && self.partner_tadig.eq_ignore_ascii_case(a))
+    }
+
+    pub fn involves(&self, tadig: &str) -> bool {
+        self.home_tadig.eq_ignore_ascii_case(tadig)
+            || self.partner_tadig.eq_ignore_ascii_case(tadig)
+    }
+
+    pub fn in_force_on(&self, day: NaiveDate) -> bool {
+        self.status == AgreementStatus::Active
+            && self.valid_from <= day
+            && self.valid_until.is_none_or(|until| until >= day)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CreateRoamingAgreement {
+    pub home_tadig: String,
+    pub partner_tadig: String,
+    pub technology: AgreementTechnology,
+    pub services: Vec<AgreementService>,
+    pub status: AgreementStatus,
+    #[serde(default)]
+    pub direction: AgreementDirection,
+    pub valid_from: NaiveDate,
+    pub valid_until: Option<NaiveDate>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct AgreementQuery {
+    pub tadig: Option<String>,
+    pub status: Option<AgreementStatus>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SteeringPartner {
+    pub tadig: String,
+    pub weight: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SteeringPreference {
+    pub home_tadig: String,
+    pub visited_iso3: String,
+    pub partners: Vec<SteeringPartner>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SetSteeringPreference {
+    pub partners: Vec<SteeringPartner>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct SteeringQuery {
+    pub home: Option<String>,
+    pub imsi: Option<String>,
+    pub iso3: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SteeredPartner {
+    pub rank: usize,
+    pub weight: u32,
+    pub operator: Arc<Operator>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SteeringResult {
+    pub home_tadig: String,
+    pub visited_iso3: String,
+    pub visited_network: Arc<Operator>,
+    pub matched_prefix: Option<String>,
+    pub partners: Vec<SteeredPartner>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractFilter {
+    Contracted,
+    Uncontracted,
+    #[default]
+    All,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct BorderCountry {
+    pub iso2: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CountryRecord {
+    pub name: String,
+    pub alpha2: String,
+    pub alpha3: String,
+    pub numeric: String,
+    pub aliases: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityIssueKind {
+    DuplicateId,
+    MalformedTadig,
+    DuplicateTadig,
+    DuplicateE212,
+    DuplicateE164,
+    IsoMismatch,
+    MalformedE212,
+    NumberingMismatch,
+    CallingCodeMismatch,
+}
+
+impl IntegrityIssueKind {
+    pub fn breaks_lookups(&self) -> bool {
+        matches!(
+            self,
+            IntegrityIssueKind::DuplicateId
+                | IntegrityIssueKind::MalformedTadig
+                | IntegrityIssueKind::DuplicateTadig
+        )
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct IntegrityIssue {
+    pub kind: IntegrityIssueKind,
+    pub value: String,
+    pub operators: Vec<u64>,
+    pub message: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct IntegrityReport {
+    pub operators_checked: usize,
+    pub clean: bool,
+    pub issues: Vec<IntegrityIssue>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ImportReport<'a> {
+    pub dry_run: bool,
+    pub committed: bool,
+    pub total: usize,
+    pub accepted: usize,
+    pub rejected: usize,
+    pub rows: Vec<ImportRowReport<'a>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOperation {
+    Baseline,
+    Create,
+    Import,
+    Put,
+    Patch,
+    Delete,
+    Restore,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OperatorVersion {
+    pub lineage: u64,
+    pub version: u32,
+    pub timestamp: DateTime<Utc>,
+    pub operation: AuditOperation,
+    pub operator: Option<Operator>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RestoreQuery {
+    pub version: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditEntry {
+    pub sequence: u64,
+    pub timestamp: DateTime<Utc>,
+    pub actor: String,
+    pub operation: AuditOperation,
+    pub tadig: String,
+    pub before: Option<Operator>,
+    pub after: Option<Operator>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct AuditQuery {
+    pub tadig: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
 #[derive(Serialize, Debug)]
 pub struct GroupOperatorsSummary {
     pub iso3: String,
@@ -69,12 +520,226 @@ pub struct GroupOperatorsSummary {
     pub operators: Vec<Operator>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct ReadQuery {
     pub imsi: Option<String>,
     pub msisdn: Option<String>,
     pub tadig: Option<String>,
     pub iso3: Option<String>,
+    #[serde(default, rename = "match")]
+    pub match_mode: MatchMode,
+    pub format: Option<ResponseFormat>,
+    pub as_of: Option<DateTime<Utc>>,
+    pub max_hops: Option<u32>,
+    pub contract: Option<ContractFilter>,
+    pub iso2: Option<String>,
+    pub country: Option<String>,
+    pub realm: Option<String>,
+    pub name: Option<String>,
+    pub sort: Option<String>,
+    pub fields: Option<String>,
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+    pub page: Option<usize>,
+    pub per_page: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct SearchQuery {
+    pub q: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchField {
+    Name,
+    Country,
+    Realm,
+    Tadig,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchMatchKind {
+    Exact,
+    Prefix,
+    Fuzzy,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SearchMatch {
+    pub query: String,
+    pub token: String,
+    pub field: SearchField,
+    pub kind: SearchMatchKind,
+    pub distance: usize,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchResult {
+    pub score: f64,
+    pub matches: Vec<SearchMatch>,
+    pub operator: Arc<Operator>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchResponse {
+    pub query: String,
+    pub total: usize,
+    pub results: Vec<SearchResult>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatorField {
+    Id,
+    Country,
+    Iso2,
+    Iso3,
+    Name,
+    Names,
+    E212,
+    E164,
+    Realm,
+    Tadig,
+}
+
+impl OperatorField {
+    pub const ALL: [OperatorField; 10] = [
+        OperatorField::Id,
+        OperatorField::Country,
+        OperatorField::Iso2,
+        OperatorField::Iso3,
+        OperatorField::Name,
+        OperatorField::Names,
+        OperatorField::E212,
+        OperatorField::E164,
+        OperatorField::Realm,
+        OperatorField::Tadig,
+    ];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            OperatorField::Id => "id",
+            OperatorField::Country => "country",
+            OperatorField::Iso2 => "iso2",
+            OperatorField::Iso3 => "iso3",
+            OperatorField::Name => "name",
+            OperatorField::Names => "names",
+            OperatorField::E212 => "e212",
+            OperatorField::E164 => "e164",
+            OperatorField::Realm => "realm",
+            OperatorField::Tadig => "tadig",
+        }
+    }
+
+    pub fn parse(key: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.key().eq_ignore_ascii_case(key.trim()))
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        !matches!(
+            self,
+            OperatorField::Names
+                | OperatorField::E212
+                | OperatorField::E164
+                | OperatorField::Realm
+                | OperatorField::Tadig
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: OperatorField,
+    pub descending: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageRequest {
+    All,
+    Cursor { offset: usize, limit: usize },
+    Page { page: usize, per_page: usize },
+}
+
+#[derive(Debug)]
+pub struct OperatorListing<'a> {
+    pub operators: Vec<&'a Operator>,
+    pub total: usize,
+    pub page: PageRequest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFormat {
+    Json,
+    Csv,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchMode {
+    #[default]
+    Strict,
+    Fuzzy,
+}
+
+#[derive(Debug, Clone)]
+pub struct OperatorLookup {
+    pub operator: Arc<Operator>,
+    pub matched_prefix: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct OperatorLookupResponse<'a> {
+    #[serde(flatten)]
+    pub operator: &'a Operator,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_mode: Option<MatchMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_prefix: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_prefix_length: Option<usize>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BatchLookupRequest {
+    pub items: Vec<BatchLookupItem>,
+    #[serde(default, rename = "match")]
+    pub match_mode: MatchMode,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct BatchLookupItem {
+    pub imsi: Option<String>,
+    pub msisdn: Option<String>,
+    pub tadig: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct BatchLookupItemResponse<'a> {
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operator: Option<&'a Operator>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_prefix: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_prefix_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct BatchLookupResponse<'a> {
+    pub match_mode: MatchMode,
+    pub total: usize,
+    pub matched: usize,
+    pub failed: usize,
+    pub results: Vec<BatchLookupItemResponse<'a>>,
 }
 
 #[derive(Debug, Clone)]
@@ -132,22 +797,81 @@ pub enum SubscriberIdKind {
     Msisdn,
 }
 
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct E212Code {
+    pub mcc: String,
+    pub mnc: String,
+}
+
+impl E212Code {
+    pub fn same_network(&self, other: &E212Code) -> bool {
+        self.mcc == other.mcc && self.mnc.parse::<u16>().ok() == other.mnc.parse::<u16>().ok()
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct MncEntry {
+    pub mnc: String,
+    pub e212: String,
+    pub operator: Arc<Operator>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct MccListing {
+    pub mcc: String,
+    pub countries: Vec<String>,
+    pub mncs: Vec<MncEntry>,
+}
+
 #[derive(Serialize, Debug)]
 pub struct RoamingPartnersResult {
     pub message: String,
+    pub max_hops: u32,
     pub bordering_countries: Vec<String>,
-    pub partners: Vec<Operator>,
+    pub partners: Vec<RoamingPartner>,
+    pub by_distance: Vec<RoamingHopGroup>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
-pub struct OperatorSizeGroups {
-    pub small_size_operators: usize,
-    pub medium_size_operators: usize,
-    pub large_size_operators: usize,
+#[derive(Serialize, Debug)]
+pub struct RoamingPartner {
+    pub hops: u32,
+    pub contracted: bool,
+    #[serde(flatten)]
+    pub operator: Operator,
+}
 
-    pub small_operators: Vec<Operator>,
-    pub medium_operators: Vec<Operator>,
-    pub large_operators: Vec<Operator>,
+#[derive(Serialize, Debug)]
+pub struct RoamingHopGroup {
+    pub hops: u32,
+    pub countries: Vec<BorderCountry>,
+    pub partners: usize,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct SizeQuery {
+    pub basis: Option<SizeBasis>,
+    #[serde(default)]
+    pub summary_only: bool,
+    pub iso3: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SizeTierSummary {
+    pub name: String,
+    pub max_score: Option<f64>,
+    pub operators: usize,
+}
+
+#[derive(Serialize, Debug)]
+pub struct OperatorSizeGroups {
+    pub basis: SizeBasis,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iso3: Option<String>,
+    pub tiers: Vec<SizeTierSummary>,
+    #[serde(flatten)]
+    pub counts: BTreeMap<String, usize>,
+    #[serde(flatten)]
+    pub members: BTreeMap<String, Vec<Arc<Operator>>>,
 }
 
 impl SubscriberIdKind {
@@ -171,7 +895,7 @@ impl SubscriberIdKind {
 pub struct OperatorDuplicateChecker<'a> {
     pub imsi_prefixes: HashSet<&'a str>,
     pub msisdn_prefixes: HashSet<&'a str>,
-    pub operator_names: HashSet<&'a str>,
+    pub operator_names: HashSet<String>,
     pub tadig_codes: HashSet<&'a str>,
 }
 
@@ -191,11 +915,8 @@ impl<'a> OperatorDuplicateChecker<'a> {
 
         let operator_names = ops
             .iter()
-            .filter_map(|op| op.name.as_ref())
-            .map(|n| {
-                let lowered = n.trim().to_lowercase();
-                Box::leak(lowered.into_boxed_str()) as &str
-            })
+            .flat_map(Operator::all_names)
+            .map(|n| n.trim().to_lowercase())
             .collect();
 
         let tadig_codes = ops
@@ -212,6 +933,17 @@ impl<'a> OperatorDuplicateChecker<'a> {
         }
     }
 
+    pub fn include(&mut self, op: &'a Operator) {
+        self.imsi_prefixes
+            .extend(op.e212.iter().flatten().map(String::as_str));
+        self.msisdn_prefixes
+            .extend(op.e164.iter().flatten().map(String::as_str));
+        self.operator_names
+            .extend(op.all_names().map(|name| name.trim().to_lowercase()));
+        self.tadig_codes
+            .extend(op.tadig.iter().flatten().map(String::as_str));
+    }
+
     pub fn exclude(&mut self, op: &Operator) {
         for code in op.e212.iter().flatten() {
             self.imsi_prefixes.remove(code.as_str());
@@ -219,7 +951,7 @@ impl<'a> OperatorDuplicateChecker<'a> {
         for code in op.e164.iter().flatten() {
             self.msisdn_prefixes.remove(code.as_str());
         }
-        if let Some(name) = &op.name {
+        for name in op.all_names() {
             let key = name.trim().to_lowercase();
             self.operator_names.remove(key.as_str());
         }
@@ -228,4 +960,3 @@ impl<'a> OperatorDuplicateChecker<'a> {
         }
     }
 }
-
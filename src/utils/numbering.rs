@@ -0,0 +1,168 @@
+use crate::utils::models::E212Code;
+use csv::StringRecord;
+use once_cell::sync::Lazy as SyncLazy;
+use regex::Regex;
+use std::collections::BTreeMap;
+
+const MCC_CSV: &str = include_str!("../../resources/mcc.csv");
+const E164_CSV: &str = include_str!("../../resources/e164.csv");
+
+pub static MCC_COUNTRIES: SyncLazy<BTreeMap<String, Vec<String>>> =
+    SyncLazy::new(|| parse_mcc_table(MCC_CSV).expect("bundled MCC table must parse"));
+
+pub static CALLING_CODE_COUNTRIES: SyncLazy<BTreeMap<String, Vec<String>>> = SyncLazy::new(|| {
+    parse_calling_code_table(E164_CSV).expect("bundled E.164 calling code table must parse")
+});
+
+static REALM_RE: SyncLazy<Regex> =
+    SyncLazy::new(|| Regex::new(r"(?i)(?:^|\.)mnc(\d{3})\.mcc(\d{3})\.3gppnetwork\.org$").unwrap());
+
+fn parse_code_table(
+    raw_csv: &str,
+    label: &str,
+    expected: &str,
+    is_valid: fn(&str) -> bool,
+) -> Result<BTreeMap<String, Vec<String>>, String> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(raw_csv.as_bytes());
+
+    let mut table: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for result in rdr.records() {
+        let record: StringRecord = result.map_err(|e| format!("CSV parse error: {}", e))?;
+        if record.len() < 2 {
+            return Err(format!(
+                "{} row has {} columns, expected 2",
+                label,
+                record.len()
+            ));
+        }
+        let code = record[0].trim();
+        if !is_valid(code) {
+            return Err(format!("{} '{}' must be {}", label, code, expected));
+        }
+        table
+            .entry(code.to_string())
+            .or_default()
+            .push(record[1].trim().to_ascii_uppercase());
+    }
+    Ok(table)
+}
+
+pub fn parse_mcc_table(raw_csv: &str) -> Result<BTreeMap<String, Vec<String>>, String> {
+    parse_code_table(raw_csv, "MCC", "exactly 3 digits", is_mcc)
+}
+
+pub fn parse_calling_code_table(raw_csv: &str) -> Result<BTreeMap<String, Vec<String>>, String> {
+    parse_code_table(raw_csv, "Calling code", "1 to 4 digits", |code| {
+        (1..=4).contains(&code.len()) && code.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+pub fn is_mcc(value: &str) -> bool {
+    value.len() == 3 && value.chars().all(|c| c.is_ascii_digit())
+}
+
+pub fn countries_for_mcc(mcc: &str) -> &'static [String] {
+    MCC_COUNTRIES.get(mcc).map_or(&[], Vec::as_slice)
+}
+
+pub fn parse_e212(code: &str) -> Option<E212Code> {
+    if !(5..=6).contains(&code.len()) || !code.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (mcc, mnc) = code.split_at(3);
+    Some(E212Code {
+        mcc: mcc.to_string(),
+        mnc: mnc.to_string(),
+    })
+}
+
+pub fn parse_realm(realm: &str) -> Option<E212Code> {
+    let captures = REALM_RE.captures(realm.trim())?;
+    Some(E212Code {
+        mcc: captures[2].to_string(),
+        mnc: captures[1].to_string(),
+    })
+}
+
+pub fn check_mcc_country(mcc: &str, iso2: &str) -> Result<(), String> {
+    let owners = countries_for_mcc(mcc);
+    if owners.is_empty() {
+        return Err(format!("MCC '{}' is not assigned to any country", mcc));
+    }
+    if owners.iter().any(|owner| owner.eq_ignore_ascii_case(iso2)) {
+        Ok(())
+    } else {
+        Err(format!(
+            "MCC '{}' belongs to {}, not {}",
+            mcc,
+            owners.join("/"),
+            iso2
+        ))
+    }
+}
+
+pub fn check_realm(realm: &str, e212: &[E212Code], iso2: &str) -> Result<(), String> {
+    let Some(code) = parse_realm(realm) else {
+        return Ok(());
+    };
+    check_mcc_country(&code.mcc, iso2)
+        .map_err(|message| format!("Realm '{}': {}", realm, message))?;
+    if e212.is_empty() || e212.iter().any(|own| own.same_network(&code)) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Realm '{}' names MCC {} / MNC {}, which matches none of the operator's E.212 codes",
+            realm, code.mcc, code.mnc
+        ))
+    }
+}
+
+pub fn normalise_msisdn(value: &str) -> String {
+    let compact: String = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '.' | '(' | ')' | '/'))
+        .collect();
+    if let Some(international) = compact.strip_prefix('+') {
+        international.to_string()
+    } else if let Some(international) = compact.strip_prefix("00") {
+        international.to_string()
+    } else {
+        compact
+    }
+}
+
+pub fn calling_code_of(prefix: &str) -> Option<(&'static str, &'static [String])> {
+    (1..=prefix.len().min(4)).rev().find_map(|length| {
+        CALLING_CODE_COUNTRIES
+            .get_key_value(prefix.get(..length)?)
+            .map(|(code, owners)| (code.as_str(), owners.as_slice()))
+    })
+}
+
+pub fn check_calling_code(prefix: &str, iso2: &str) -> Result<(), String> {
+    let has_calling_code = CALLING_CODE_COUNTRIES
+        .values()
+        .any(|owners| owners.iter().any(|owner| owner.eq_ignore_ascii_case(iso2)));
+    if !has_calling_code {
+        return Ok(());
+    }
+    let Some((code, owners)) = calling_code_of(prefix) else {
+        return Err(format!(
+            "E.164 prefix '{}' does not start with a country calling code",
+            prefix
+        ));
+    };
+    if owners.iter().any(|owner| owner.eq_ignore_ascii_case(iso2)) {
+        Ok(())
+    } else {
+        Err(format!(
+            "E.164 prefix '{}' belongs to +{} ({}), not {}",
+            prefix,
+            code,
+            owners.join("/"),
+            iso2
+        ))
+    }
+}
@@ -1,23 +1,92 @@
 use crate::handlers::{
+    admin::{handle_integrity, handle_reload},
+    audit::handle_get_audit_log,
+    batch_lookup::handle_batch_lookup,
+    bulk_import::{handle_bulk_import, handle_bulk_import_csv},
+    countries::handle_list_countries,
     create::handle_create_operator,
-    delete::handle_delete_operator,
+    delete::{handle_delete_operator, handle_delete_operator_by_id},
     find_roaming_partners::handle_find_roaming_partners,
     get_operators_by_e212_count::handle_get_operators_by_e212_count,
     group_operators_by_iso3::handle_get_country_mapping,
+    history::{
+        handle_get_operator_history, handle_get_operator_history_by_id, handle_restore_operator,
+        handle_restore_operator_by_id,
+    },
+    mcc::handle_list_mncs,
     network_names::handle_network_names,
-    read::handle_get_operator,
-    update::{handle_update_by_patch, handle_update_by_put},
+    read::{handle_get_operator, handle_get_operator_by_id},
+    roaming_agreements::{
+        handle_create_agreement, handle_delete_agreement, handle_get_agreement,
+        handle_list_agreements, handle_replace_agreement,
+    },
+    search::handle_search_operators,
+    steering::{
+        handle_delete_steering_preference, handle_get_steering_preference,
+        handle_list_steering_preferences, handle_set_steering_preference, handle_steer,
+    },
+    update::{
+        handle_update_by_patch, handle_update_by_patch_id, handle_update_by_put,
+        handle_update_by_put_id,
+    },
 };
-use actix_web::web;
+use actix_web::{guard, http::header, web};
+
+const BULK_PAYLOAD_LIMIT: usize = 16 * 1024 * 1024;
+
+fn is_csv_payload(ctx: &guard::GuardContext) -> bool {
+    ctx.head()
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("text/csv"))
+}
 
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/api/v1/operators")
             .route("", web::get().to(handle_get_operator))
             .route("", web::post().to(handle_create_operator))
+            .service(
+                web::resource("/lookup:batch")
+                    .app_data(web::JsonConfig::default().limit(BULK_PAYLOAD_LIMIT))
+                    .route(web::post().to(handle_batch_lookup)),
+            )
+            .service(
+                web::resource("/import")
+                    .app_data(web::JsonConfig::default().limit(BULK_PAYLOAD_LIMIT))
+                    .app_data(web::PayloadConfig::new(BULK_PAYLOAD_LIMIT))
+                    .route(
+                        web::post()
+                            .guard(guard::fn_guard(is_csv_payload))
+                            .to(handle_bulk_import_csv),
+                    )
+                    .route(web::post().to(handle_bulk_import)),
+            )
+            .route("/by-id/{id}", web::get().to(handle_get_operator_by_id))
+            .route("/by-id/{id}", web::put().to(handle_update_by_put_id))
+            .route("/by-id/{id}", web::patch().to(handle_update_by_patch_id))
+            .route(
+                "/by-id/{id}",
+                web::delete().to(handle_delete_operator_by_id),
+            )
+            .route(
+                "/by-id/{id}/history",
+                web::get().to(handle_get_operator_history_by_id),
+            )
+            .route(
+                "/by-id/{id}/restore",
+                web::post().to(handle_restore_operator_by_id),
+            )
             .route("/{tadig}", web::put().to(handle_update_by_put))
             .route("/{tadig}", web::patch().to(handle_update_by_patch))
             .route("/{tadig}", web::delete().to(handle_delete_operator))
+            .route(
+                "/{tadig}/history",
+                web::get().to(handle_get_operator_history),
+            )
+            .route("/{tadig}/restore", web::post().to(handle_restore_operator))
+            .route("/search", web::get().to(handle_search_operators))
             .route("/network-names", web::get().to(handle_network_names))
             .route(
                 "/by-countries-operators",
@@ -32,4 +101,34 @@ pub fn configure_routes(cfg: &mut web::ServiceConfig) {
                 web::get().to(handle_find_roaming_partners),
             ),
     );
+    cfg.route("/api/v1/audit", web::get().to(handle_get_audit_log));
+    cfg.route("/api/v1/countries", web::get().to(handle_list_countries));
+    cfg.route("/api/v1/mcc/{mcc}/mncs", web::get().to(handle_list_mncs));
+    cfg.service(
+        web::scope("/api/v1/roaming-agreements")
+            .route("", web::get().to(handle_list_agreements))
+            .route("", web::post().to(handle_create_agreement))
+            .route("/{id}", web::get().to(handle_get_agreement))
+            .route("/{id}", web::put().to(handle_replace_agreement))
+            .route("/{id}", web::delete().to(handle_delete_agreement)),
+    );
+    cfg.route("/api/v1/steering", web::get().to(handle_steer));
+    cfg.service(
+        web::scope("/api/v1/steering/preferences")
+            .route("", web::get().to(handle_list_steering_preferences))
+            .route(
+                "/{home}/{iso3}",
+                web::get().to(handle_get_steering_preference),
+            )
+            .route(
+                "/{home}/{iso3}",
+                web::put().to(handle_set_steering_preference),
+            )
+            .route(
+                "/{home}/{iso3}",
+                web::delete().to(handle_delete_steering_preference),
+            ),
+    );
+    cfg.route("/api/v1/admin/reload", web::post().to(handle_reload));
+    cfg.route("/api/v1/admin/integrity", web::get().to(handle_integrity));
 }
@@ -1,13 +1,17 @@
 use crate::utils::{
+    config::IntegrityMode,
+    countries::{find_country_by_alpha2, find_country_by_alpha3, resolve_country},
     error_responses::ErrorResponse,
     models::{
-        Operator, OperatorDuplicateChecker, OperatorValidationError, PatchOperator,
-        SubscriberIdKind,
+        E212Code, Operator, OperatorDuplicateChecker, OperatorName, OperatorValidationError,
+        PatchOperator, SubscriberIdKind,
     },
+    numbering::{check_calling_code, check_mcc_country, check_realm, parse_e212},
 };
+use log::warn;
 use once_cell::sync::Lazy as SyncLazy;
 use regex::Regex;
-use std::{borrow::Borrow, collections::HashSet, fmt::Display, hash::Hash, sync::Arc};
+use std::{borrow::Borrow, collections::HashSet, fmt::Display, hash::Hash};
 
 pub static TADIG_RE: SyncLazy<Regex> =
     SyncLazy::new(|| Regex::new(r"^[A-Za-z0-9_]{5,9}$").unwrap());
@@ -40,105 +44,65 @@ pub fn validate_digits(
     }
 }
 
-pub fn validate_characters_and_spaces(
-    field_name: &str,
-    field_value: &str,
-) -> Result<(), OperatorValidationError> {
-    if field_value
-        .chars()
-        .all(|c| c.is_ascii_alphabetic() || c.is_whitespace())
-    {
-        Ok(())
-    } else {
-        Err(OperatorValidationError::FieldValidationError {
-            field: field_name.to_string(),
-            message: format!("{} must contain only letters and spaces", field_name),
-            received: Some(field_value.to_string()),
-        })
-    }
-}
-
-pub fn format_country_name(raw_country: &str) -> Result<String, ErrorResponse> {
-    let name = validate_non_empty("country", raw_country).map_err(ErrorResponse::Validation)?;
-    validate_characters_and_spaces("country", name).map_err(ErrorResponse::Validation)?;
-    let mut chars = name.chars();
-    let formatted =
-        chars.next().unwrap().to_uppercase().to_string() + &chars.as_str().to_lowercase();
-    Ok(formatted)
-}
-
 pub fn validate_iso_fields(patch: &PatchOperator) -> Result<(), ErrorResponse> {
-    if let Some(ref iso2) = patch.iso2 {
-        if iso2.len() != 2
+    if let Some(ref iso2) = patch.iso2
+        && (iso2.len() != 2
             || !iso2
                 .chars()
-                .all(|arg0: char| char::is_ascii_alphabetic(&arg0))
-        {
-            return Err(OperatorValidationError::FieldValidationError {
-                field: "iso2".to_string(),
-                message: "ISO2 must be exactly 2 letters".to_string(),
-                received: Some(iso2.clone()),
-            }
-            .into());
+                .all(|arg0: char| char::is_ascii_alphabetic(&arg0)))
+    {
+        return Err(OperatorValidationError::FieldValidationError {
+            field: "iso2".to_string(),
+            message: "ISO2 must be exactly 2 letters".to_string(),
+            received: Some(iso2.clone()),
         }
+        .into());
     }
-    if let Some(ref iso3) = patch.iso3 {
-        if iso3.len() != 3
+    if let Some(ref iso3) = patch.iso3
+        && (iso3.len() != 3
             || !iso3
                 .chars()
-                .all(|arg0: char| char::is_ascii_alphabetic(&arg0))
-        {
-            return Err(OperatorValidationError::FieldValidationError {
-                field: "iso3".to_string(),
-                message: "ISO3 must be exactly 3 letters".to_string(),
-                received: Some(iso3.clone()),
-            }
-            .into());
+                .all(|arg0: char| char::is_ascii_alphabetic(&arg0)))
+    {
+        return Err(OperatorValidationError::FieldValidationError {
+            field: "iso3".to_string(),
+            message: "ISO3 must be exactly 3 letters".to_string(),
+            received: Some(iso3.clone()),
         }
+        .into());
     }
     Ok(())
 }
 
 pub fn determine_updated_codes(
-    all_operators: &[Arc<Operator>],
     patch: &PatchOperator,
     current: &Operator,
 ) -> Result<(String, String, String), ErrorResponse> {
-    if let Some(ref iso3) = patch.iso3 {
-        if let Some(op) = all_operators.iter().find(|o| &o.iso3 == iso3) {
-            return Ok((op.country.clone(), op.iso2.clone(), op.iso3.clone()));
-        }
-        return Err(ErrorResponse::NotFound {
+    let resolved = if let Some(ref iso3) = patch.iso3 {
+        find_country_by_alpha3(iso3).ok_or_else(|| ErrorResponse::NotFound {
             field: "iso3".to_string(),
             received: iso3.clone(),
-            expected: "an existing ISO3 code".to_string(),
-        });
-    }
-    if let Some(ref iso2) = patch.iso2 {
-        if let Some(op) = all_operators.iter().find(|o| &o.iso2 == iso2) {
-            return Ok((op.country.clone(), op.iso2.clone(), op.iso3.clone()));
-        }
-        return Err(ErrorResponse::NotFound {
+            expected: "an ISO3 code from the ISO 3166-1 table".to_string(),
+        })?
+    } else if let Some(ref iso2) = patch.iso2 {
+        find_country_by_alpha2(iso2).ok_or_else(|| ErrorResponse::NotFound {
             field: "iso2".to_string(),
             received: iso2.clone(),
-            expected: "an existing ISO2 code".to_string(),
-        });
-    }
-    if let Some(ref country_raw) = patch.country {
-        let formatted = format_country_name(country_raw)?;
-        if let Some(op) = all_operators.iter().find(|o| o.country == formatted) {
-            return Ok((op.country.clone(), op.iso2.clone(), op.iso3.clone()));
-        }
-        return Err(ErrorResponse::NotFound {
-            field: "country".to_string(),
-            received: formatted.clone(),
-            expected: "an existing country".to_string(),
-        });
-    }
+            expected: "an ISO2 code from the ISO 3166-1 table".to_string(),
+        })?
+    } else if let Some(ref country_raw) = patch.country {
+        resolve_country("country", country_raw)?
+    } else {
+        return Ok((
+            current.country.clone(),
+            current.iso2.clone(),
+            current.iso3.clone(),
+        ));
+    };
     Ok((
-        current.country.clone(),
-        current.iso2.clone(),
-        current.iso3.clone(),
+        resolved.name.clone(),
+        resolved.alpha2.clone(),
+        resolved.alpha3.clone(),
     ))
 }
 
@@ -172,17 +136,8 @@ pub fn validate_patch_fields(
         }
     }
 
-    if let Some(Some(name)) = patch.name.as_ref() {
-        let key = name.trim().to_lowercase();
-        if dup.operator_names.contains(key.as_str()) {
-            return Err(OperatorValidationError::FieldValidationError {
-                field: "name".to_string(),
-                message: format!("Operator name '{}' already exists", name),
-                received: Some(name.clone()),
-            }
-            .into());
-        }
-    }
+    let patched_name = patch.name.clone().flatten();
+    validate_unique_operator_name(&patched_name, &patch.names, &dup.operator_names)?;
 
     if let Some(list) = patch.tadig.as_deref() {
         for code in list {
@@ -207,19 +162,6 @@ pub fn validate_patch_fields(
     Ok(())
 }
 
-pub fn find_operator_by_country<'a>(
-    list: &'a [Operator],
-    country: &str,
-) -> Result<&'a Operator, ErrorResponse> {
-    list.iter().find(|op| op.country == country).ok_or_else(|| {
-        ErrorResponse::Validation(OperatorValidationError::InvalidCountry {
-            field: "country".to_string(),
-            received: country.to_string(),
-            expected: "an existing country".to_string(),
-        })
-    })
-}
-
 pub fn validate_unique_numeric_codes<B>(
     field: &str,
     _code_type: &str,
@@ -293,16 +235,34 @@ where
 
 pub fn validate_unique_operator_name<B>(
     operator_name: &Option<String>,
+    typed_names: &Option<Vec<OperatorName>>,
     existing_operator_names: &HashSet<B>,
 ) -> Result<(), ErrorResponse>
 where
     B: Borrow<str> + Eq + Hash,
 {
-    if let Some(name) = operator_name {
+    let mut own_names = HashSet::new();
+    for typed in typed_names.iter().flatten() {
+        let key = validate_non_empty("names", &typed.value)?.to_lowercase();
+        if !own_names.insert(key) {
+            return Err(OperatorValidationError::FieldValidationError {
+                field: "names".to_string(),
+                message: format!("Operator name '{}' is listed more than once", typed.value),
+                received: Some(typed.value.clone()),
+            }
+            .into());
+        }
+    }
+
+    let candidates = operator_name
+        .iter()
+        .map(|name| ("name", name))
+        .chain(typed_names.iter().flatten().map(|n| ("names", &n.value)));
+    for (field, name) in candidates {
         let key = name.trim().to_lowercase();
         if existing_operator_names.contains(key.as_str()) {
             return Err(OperatorValidationError::FieldValidationError {
-                field: "name".to_string(),
+                field: field.to_string(),
                 message: format!("Operator name '{}' already exists", name),
                 received: Some(name.clone()),
             }
@@ -312,6 +272,64 @@ where
     Ok(())
 }
 
+pub fn validate_numbering_plan(
+    e212: Option<&[String]>,
+    realm: Option<&[String]>,
+    iso2: &str,
+) -> Result<(), ErrorResponse> {
+    let field_error = |field: &str, received: &str, message: String| -> ErrorResponse {
+        OperatorValidationError::FieldValidationError {
+            field: field.to_string(),
+            message,
+            received: Some(received.to_string()),
+        }
+        .into()
+    };
+
+    let mut parsed: Vec<E212Code> = Vec::new();
+    for code in e212.unwrap_or_default() {
+        let Some(e212_code) = parse_e212(code) else {
+            return Err(field_error(
+                "e212",
+                code,
+                "E.212 code must be a 3-digit MCC followed by a 2- or 3-digit MNC".to_string(),
+            ));
+        };
+        check_mcc_country(&e212_code.mcc, iso2)
+            .map_err(|message| field_error("e212", code, message))?;
+        parsed.push(e212_code);
+    }
+
+    for value in realm.unwrap_or_default() {
+        check_realm(value, &parsed, iso2)
+            .map_err(|message| field_error("realm", value, message))?;
+    }
+    Ok(())
+}
+
+pub fn validate_msisdn_country_codes(
+    e164: Option<&[String]>,
+    iso2: &str,
+    mode: IntegrityMode,
+) -> Result<(), ErrorResponse> {
+    for code in e164.unwrap_or_default() {
+        let Err(message) = check_calling_code(code, iso2) else {
+            continue;
+        };
+        if mode == IntegrityMode::Lenient {
+            warn!("E.164 validation: {}", message);
+            continue;
+        }
+        return Err(OperatorValidationError::FieldValidationError {
+            field: "e164".to_string(),
+            message,
+            received: Some(code.clone()),
+        }
+        .into());
+    }
+    Ok(())
+}
+
 pub fn validate_iso3_code(field_name: &str, iso3: &str) -> Result<String, OperatorValidationError> {
     let uppercase_iso3 = iso3.to_ascii_uppercase();
 
@@ -370,3 +388,23 @@ pub fn format_network_name(network_name_input: &str) -> String {
 
     first + &rest
 }
+
+pub fn validate_if_match(current: &Operator, if_match: Option<&str>) -> Result<(), ErrorResponse> {
+    let Some(if_match) = if_match else {
+        return Ok(());
+    };
+    let current_etag = current.etag();
+    let matches = if_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate == current_etag);
+    if matches {
+        Ok(())
+    } else {
+        Err(ErrorResponse::PreconditionFailed {
+            field: "If-Match".to_string(),
+            received: if_match.to_string(),
+            expected: current_etag,
+        })
+    }
+}
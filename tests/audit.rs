@@ -0,0 +1,155 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::{BASE_URL, client, operator, state_with};
+use operator_mappings_api::{
+    app_state::audit::AuditLog,
+    operators::audit::query_audit_log,
+    utils::models::{AuditOperation, AuditQuery},
+};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+use std::env;
+
+#[tokio::test]
+async fn test_audit_log_filters_by_tadig_and_time() {
+    let state = state_with(Vec::new());
+    let before = operator("RO", "RomNet", "ROM05");
+    let after = operator("RO", "RomNet 2", "ROM05");
+
+    state
+        .audit_log
+        .record(
+            "alice",
+            AuditOperation::Create,
+            "ROM05",
+            None,
+            Some(&before),
+        )
+        .await
+        .unwrap();
+    state
+        .audit_log
+        .record(
+            "bob",
+            AuditOperation::Patch,
+            "ROM05",
+            Some(&before),
+            Some(&after),
+        )
+        .await
+        .unwrap();
+    state
+        .audit_log
+        .record(
+            "bob",
+            AuditOperation::Create,
+            "IRL01",
+            None,
+            Some(&operator("IE", "Eir", "IRL01")),
+        )
+        .await
+        .unwrap();
+
+    let query = AuditQuery {
+        tadig: Some("ROM05".to_string()),
+        ..Default::default()
+    };
+    let entries = query_audit_log(&state, &query).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].actor, "bob");
+    assert_eq!(entries[1].operation, AuditOperation::Patch);
+    assert_eq!(
+        entries[1].before.as_ref().unwrap().name.as_deref(),
+        Some("RomNet")
+    );
+    assert_eq!(
+        entries[1].after.as_ref().unwrap().name.as_deref(),
+        Some("RomNet 2")
+    );
+
+    let query = AuditQuery {
+        from: Some(Utc::now() + Duration::hours(1)),
+        ..Default::default()
+    };
+    assert!(query_audit_log(&state, &query).unwrap().is_empty());
+
+    let query = AuditQuery {
+        from: Some(Utc::now()),
+        to: Some(Utc::now() - Duration::hours(1)),
+        ..Default::default()
+    };
+    assert!(query_audit_log(&state, &query).is_err());
+}
+
+#[tokio::test]
+async fn test_audit_log_is_reloaded_from_file() {
+    let path = env::temp_dir().join(format!("audit_log_{}.jsonl", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    let _ = std::fs::remove_file(&path);
+
+    let log = AuditLog::open(path.clone()).await.unwrap();
+    log.record(
+        "alice",
+        AuditOperation::Delete,
+        "ROM05",
+        Some(&operator("RO", "RomNet", "ROM05")),
+        None,
+    )
+    .await
+    .unwrap();
+    log.record(
+        "alice",
+        AuditOperation::Create,
+        "IRL01",
+        None,
+        Some(&operator("IE", "Eir", "IRL01")),
+    )
+    .await
+    .unwrap();
+
+    let reopened = AuditLog::open(path.clone()).await.unwrap();
+    let entries = reopened.entries.read().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].sequence, 1);
+    assert_eq!(entries[1].sequence, 2);
+    assert_eq!(entries[0].operation, AuditOperation::Delete);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_audit_endpoint_records_actor() {
+    let body = json!({
+        "country": "Ireland",
+        "name":    "Audited Operator",
+        "e212":    ["27299"],
+        "tadig":   ["IRLAU"]
+    });
+    let resp = client()
+        .post(format!("{}/operators", &*BASE_URL))
+        .header("X-Actor", "audit-test")
+        .json(&body)
+        .send()
+        .await
+        .expect("POST /operators failed");
+    assert!(
+        resp.status().is_success() || resp.status() == StatusCode::CONFLICT,
+        "IRLAU setup failed: {}",
+        resp.status()
+    );
+
+    let resp = client()
+        .get(format!("{}/audit", &*BASE_URL))
+        .query(&[("tadig", "IRLAU")])
+        .send()
+        .await
+        .expect("GET /audit failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let entries: Value = resp.json().await.expect("Invalid JSON");
+    let entries = entries.as_array().unwrap();
+    assert!(
+        entries
+            .iter()
+            .any(|e| e["actor"] == "audit-test" && e["operation"] == "create")
+    );
+}
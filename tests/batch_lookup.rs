@@ -0,0 +1,91 @@
+mod common;
+
+use common::{BASE_URL, client, codes, operator, state_with};
+use operator_mappings_api::{
+    app_state::AppState,
+    operators::batch_lookup::lookup_operators_batch,
+    utils::models::{BatchLookupItem, MatchMode, Operator},
+};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+fn setup_state() -> AppState {
+    state_with(vec![Operator {
+        e212: codes(&["22605"]),
+        e164: codes(&["40770"]),
+        ..operator("RO", "RomNet", "ROM05")
+    }])
+}
+
+#[test]
+fn test_batch_lookup_reports_per_item_outcomes() {
+    let state = setup_state();
+    let items = vec![
+        BatchLookupItem {
+            imsi: Some("226051234567890".to_string()),
+            ..Default::default()
+        },
+        BatchLookupItem {
+            msisdn: Some("40A70".to_string()),
+            ..Default::default()
+        },
+        BatchLookupItem {
+            tadig: Some("ROM05".to_string()),
+            ..Default::default()
+        },
+        BatchLookupItem {
+            imsi: Some("999999".to_string()),
+            ..Default::default()
+        },
+        BatchLookupItem::default(),
+    ];
+
+    let outcomes = lookup_operators_batch(&state, &items, MatchMode::Strict).unwrap();
+    assert_eq!(outcomes.len(), 5);
+
+    let imsi_hit = outcomes[0].as_ref().expect("IMSI must resolve");
+    assert!(imsi_hit.operator.has_tadig("ROM05"));
+    assert_eq!(imsi_hit.matched_prefix.as_deref(), Some("22605"));
+
+    let err = outcomes[1].as_ref().unwrap_err().to_string();
+    assert!(err.contains("InvalidMsisdnError"), "got `{}`", err);
+
+    assert!(outcomes[2].is_ok());
+
+    let err = outcomes[3].as_ref().unwrap_err().to_string();
+    assert!(err.contains("not found"), "got `{}`", err);
+
+    assert!(outcomes[4].is_err());
+}
+
+#[test]
+fn test_batch_lookup_rejects_empty_batch() {
+    let state = setup_state();
+    assert!(lookup_operators_batch(&state, &[], MatchMode::Strict).is_err());
+}
+
+#[tokio::test]
+async fn test_batch_lookup_endpoint_returns_results_in_order() {
+    let body = json!({
+        "items": [
+            { "imsi": "3368900890017" },
+            { "msisdn": "frgr" },
+            { "tadig": "LBN_GEO" }
+        ]
+    });
+    let resp = client()
+        .post(format!("{}/operators/lookup:batch", &*BASE_URL))
+        .json(&body)
+        .send()
+        .await
+        .expect("POST /operators/lookup:batch failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body["total"].as_u64().unwrap(), 3);
+    let results = body["results"].as_array().unwrap();
+    assert!(results[0]["operator"].is_object());
+    assert!(results[0]["matched_prefix"].is_string());
+    assert_eq!(results[1]["status"].as_u64().unwrap(), 400);
+    assert!(results[2]["operator"].is_object());
+}
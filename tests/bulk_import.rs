@@ -0,0 +1,70 @@
+mod common;
+
+use common::{codes, create_input, operator, state_with};
+use operator_mappings_api::{
+    app_state::AppState,
+    operators::crud_operations::bulk_import::import_operators,
+    utils::models::{CreateOperator, Operator},
+};
+
+fn setup_state() -> AppState {
+    state_with(vec![Operator {
+        e212: codes(&["27201"]),
+        e164: codes(&["35385"]),
+        ..operator("IE", "IrishCom", "IRL01")
+    }])
+}
+
+fn row(name: &str, e212: &str, tadig: &str) -> CreateOperator {
+    create_input("Ireland", name, e212, tadig)
+}
+
+#[test]
+fn test_import_commits_all_valid_rows() {
+    let state = setup_state();
+    let rows = vec![
+        row("Three", "27205", "IRLH3"),
+        row("Vodafone", "27206", "IRLEC"),
+    ];
+
+    let outcome = import_operators(&state, &rows, false).unwrap();
+
+    assert!(outcome.committed);
+    assert!(outcome.rows.iter().all(|r| r.result.is_ok()));
+    let ops = state.operators.read().unwrap();
+    assert_eq!(ops.len(), 3);
+    let imported = ops.iter().find(|o| o.has_tadig("IRLH3")).unwrap();
+    assert_eq!(imported.iso3, "IRL");
+}
+
+#[test]
+fn test_import_rejects_rows_conflicting_with_each_other() {
+    let state = setup_state();
+    let rows = vec![
+        row("Three", "27205", "IRLH3"),
+        row("Vodafone", "27206", "IRLH3"),
+        row("Tesco", "27201", "IRLTM"),
+    ];
+
+    let outcome = import_operators(&state, &rows, false).unwrap();
+
+    assert!(!outcome.committed);
+    assert!(outcome.rows[0].result.is_ok());
+    let err = outcome.rows[1].result.as_ref().unwrap_err().to_string();
+    assert!(err.contains("DuplicateTadigError"), "got `{}`", err);
+    let err = outcome.rows[2].result.as_ref().unwrap_err().to_string();
+    assert!(err.contains("DuplicateImsiError"), "got `{}`", err);
+    assert_eq!(state.operators.read().unwrap().len(), 1);
+}
+
+#[test]
+fn test_import_dry_run_does_not_commit() {
+    let state = setup_state();
+    let rows = vec![row("Three", "27205", "IRLH3")];
+
+    let outcome = import_operators(&state, &rows, true).unwrap();
+
+    assert!(!outcome.committed);
+    assert!(outcome.rows[0].result.is_ok());
+    assert_eq!(state.operators.read().unwrap().len(), 1);
+}
@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+use dotenv::dotenv;
+use once_cell::sync::Lazy;
+use operator_mappings_api::{
+    app_state::AppState,
+    utils::{
+        countries::find_country_by_alpha2,
+        models::{CreateOperator, Operator},
+    },
+};
+use reqwest::Client;
+use std::{collections::HashMap, env};
+
+pub static BASE_URL: Lazy<String> = Lazy::new(|| {
+    dotenv().ok();
+    env::var("API_TEST_BASE_URL").unwrap_or_else(|_| "http://localhost:8080/api/v1".to_string())
+});
+
+pub fn client() -> Client {
+    Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .unwrap()
+}
+
+pub fn codes(values: &[&str]) -> Option<Vec<String>> {
+    Some(values.iter().map(|value| value.to_string()).collect())
+}
+
+pub fn operator(iso2: &str, name: &str, tadig: &str) -> Operator {
+    let country = find_country_by_alpha2(iso2).expect("fixture country must be in the ISO table");
+    Operator {
+        id: 0,
+        country: country.name.clone(),
+        iso2: country.alpha2.clone(),
+        iso3: country.alpha3.clone(),
+        name: Some(name.to_string()),
+        names: None,
+        e212: None,
+        e164: None,
+        realm: None,
+        tadig: codes(&[tadig]),
+    }
+}
+
+pub fn state_with(operators: Vec<Operator>) -> AppState {
+    AppState::new(operators, "test_mapping.json".to_string(), HashMap::new())
+}
+
+pub fn create_input(country: &str, name: &str, e212: &str, tadig: &str) -> CreateOperator {
+    CreateOperator {
+        country: country.to_string(),
+        e164: None,
+        e212: codes(&[e212]),
+        name: Some(name.to_string()),
+        names: None,
+        realm: None,
+        tadig: codes(&[tadig]),
+    }
+}
@@ -0,0 +1,12 @@
+use operator_mappings_api::utils::config::Config;
+use std::env;
+
+#[test]
+fn test_invalid_size_tiers_fail_config_load() {
+    // Only test in this binary, so no other thread reads the environment concurrently.
+    unsafe { env::set_var("OPERATOR_SIZE_TIERS", "small:three,large") };
+
+    let err = Config::from_env().unwrap_err();
+    assert!(err.contains("OPERATOR_SIZE_TIERS"), "got `{}`", err);
+    assert!(err.contains("non-numeric maximum"), "got `{}`", err);
+}
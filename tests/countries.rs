@@ -0,0 +1,116 @@
+mod common;
+
+use common::{BASE_URL, client, codes, create_input, operator, state_with};
+use operator_mappings_api::{
+    app_state::AppState,
+    operators::crud_operations::{create::create_operator, update::update_operator_by_patch},
+    utils::{
+        countries::{find_country, normalise_country_name, resolve_country},
+        models::{Operator, PatchOperator},
+    },
+};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+fn setup_state() -> AppState {
+    state_with(vec![Operator {
+        e212: codes(&["27201"]),
+        ..operator("IE", "IrishCom", "IRL01")
+    }])
+}
+
+#[test]
+fn test_countries_resolve_by_name_alias_and_code() {
+    let uk = find_country("  uk ").unwrap();
+    assert_eq!(uk.alpha2, "GB");
+    assert_eq!(uk.alpha3, "GBR");
+    assert_eq!(uk.numeric, "826");
+    assert_eq!(find_country("United  Kingdom").unwrap().alpha2, "GB");
+    assert_eq!(find_country("irl").unwrap().name, "Ireland");
+    assert_eq!(find_country("ie").unwrap().alpha3, "IRL");
+    assert!(find_country("Narnia").is_none());
+    assert!(resolve_country("country", "Narnia").is_err());
+    assert!(resolve_country("country", "   ").is_err());
+}
+
+#[test]
+fn test_country_names_match_ignoring_accents_and_punctuation() {
+    assert_eq!(
+        normalise_country_name("  Côte d'Ivoire "),
+        normalise_country_name("COTE D’IVOIRE")
+    );
+    assert_eq!(normalise_country_name("Gambia (the)"), "gambia");
+
+    assert_eq!(find_country("Côte d'Ivoire").unwrap().alpha2, "CI");
+    assert!(find_country("cote divoire").is_none());
+    assert_eq!(find_country("guinea bissau").unwrap().name, "Guinea-Bissau");
+    assert_eq!(find_country("The Gambia").unwrap().alpha2, "GM");
+    assert_eq!(find_country("SAINT BARTHÉLEMY").unwrap().alpha2, "BL");
+    assert_eq!(
+        find_country("bolivia, plurinational state of")
+            .unwrap()
+            .name,
+        "Bolivia (Plurinational State Of)"
+    );
+}
+
+#[test]
+fn test_create_stores_canonical_country_name() {
+    let state = setup_state();
+    let created = create_operator(
+        &state,
+        create_input("cote d'ivoire", "Orange CI", "61203", "CIVOR"),
+    )
+    .unwrap();
+    assert_eq!(created.country, "Cote d’Ivoire");
+    assert_eq!(created.iso3, "CIV");
+}
+
+#[test]
+fn test_create_first_operator_in_new_country() {
+    let state = setup_state();
+    let created =
+        create_operator(&state, create_input("iceland", "Siminn", "27401", "ISLSI")).unwrap();
+    assert_eq!(created.country, "Iceland");
+    assert_eq!(created.iso2, "IS");
+    assert_eq!(created.iso3, "ISL");
+}
+
+#[test]
+fn test_patch_resolves_codes_from_table() {
+    let state = setup_state();
+    let patch = PatchOperator {
+        iso2: Some("GB".to_string()),
+        e212: Some(vec!["23415".to_string()]),
+        ..Default::default()
+    };
+    let update = update_operator_by_patch(&state, "IRL01", patch, None).unwrap();
+    assert_eq!(update.updated.iso2, "GB");
+    assert_eq!(update.updated.iso3, "GBR");
+    assert_eq!(
+        update.updated.country,
+        "United Kingdom of Great Britain and Northern Ireland"
+    );
+
+    let patch = PatchOperator {
+        iso3: Some("XXX".to_string()),
+        ..Default::default()
+    };
+    assert!(update_operator_by_patch(&state, "IRL01", patch, None).is_err());
+}
+
+#[tokio::test]
+async fn test_list_countries_endpoint() {
+    let resp = client()
+        .get(format!("{}/countries", &*BASE_URL))
+        .send()
+        .await
+        .expect("GET /countries failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let countries: Value = resp.json().await.expect("Invalid JSON");
+    let countries = countries.as_array().unwrap();
+    assert_eq!(countries.len(), 249);
+    let ireland = countries.iter().find(|c| c["alpha2"] == "IE").unwrap();
+    assert_eq!(ireland["alpha3"], "IRL");
+    assert_eq!(ireland["numeric"], "372");
+}
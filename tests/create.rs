@@ -109,3 +109,20 @@ fn test_duplicate_tadig_code() {
         _ => panic!("Expected DuplicateTadigError"),
     }
 }
+
+#[test]
+fn test_duplicate_operator_name_is_case_insensitive() {
+    let patch = PatchOperator {
+        name: Some(Some("  MOVISTAR ".to_string())),
+        ..Default::default()
+    };
+
+    let mut dup = OperatorDuplicateChecker::default();
+    dup.operator_names.insert("movistar".to_string());
+
+    let result = validate_patch_fields(&dup, &patch);
+    match result {
+        Err(err) => assert!(err.to_string().contains("already exists")),
+        _ => panic!("Expected a duplicate name error"),
+    }
+}
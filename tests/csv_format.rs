@@ -0,0 +1,103 @@
+mod common;
+
+use common::{BASE_URL, client, codes, operator};
+use operator_mappings_api::utils::{
+    csv_format::{operators_from_csv, operators_to_csv},
+    models::{NameKind, Operator, OperatorName},
+};
+use reqwest::{StatusCode, header};
+
+fn sample_operator() -> Operator {
+    Operator {
+        e212: codes(&["72207", "722010"]),
+        realm: codes(&["epc.mnc007.mcc722.3gppnetwork.org"]),
+        tadig: codes(&["ARGTM", "ARG01"]),
+        ..operator("AR", "Telefónica Móviles Argentina S.A.", "ARGTM")
+    }
+}
+
+#[test]
+fn test_csv_round_trip_keeps_multi_valued_fields() {
+    let op = sample_operator();
+    let raw_csv = operators_to_csv(&[&op]).unwrap();
+
+    assert!(raw_csv.starts_with("country,iso2,iso3,name,e212,e164,realm,tadig"));
+    assert!(raw_csv.contains("72207;722010"));
+
+    let rows = operators_from_csv(&raw_csv).unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].country, "Argentina");
+    assert_eq!(rows[0].name, op.name);
+    assert_eq!(rows[0].e212, op.e212);
+    assert_eq!(rows[0].e164, None);
+    assert_eq!(rows[0].realm, op.realm);
+    assert_eq!(rows[0].tadig, op.tadig);
+}
+
+#[test]
+fn test_csv_round_trip_escapes_separators_in_values() {
+    let mut op = sample_operator();
+    op.realm = Some(vec![
+        "ims;legacy.example.org".to_string(),
+        r"C:\realms\ar".to_string(),
+    ]);
+    op.names = Some(vec![
+        OperatorName {
+            kind: NameKind::Brand,
+            value: "Movistar; Tu Mundo".to_string(),
+        },
+        OperatorName {
+            kind: NameKind::Historic,
+            value: "Unifon: Movil".to_string(),
+        },
+    ]);
+    let raw_csv = operators_to_csv(&[&op]).unwrap();
+    assert!(raw_csv.contains(r"ims\;legacy.example.org"));
+
+    let rows = operators_from_csv(&raw_csv).unwrap();
+    assert_eq!(rows[0].realm, op.realm);
+    assert_eq!(rows[0].names, op.names);
+    assert_eq!(rows[0].tadig, op.tadig);
+}
+
+#[test]
+fn test_csv_import_requires_country_column() {
+    let result = operators_from_csv("name,tadig\nVivo,BRA01\n");
+    assert!(result.is_err(), "rows without a country must be rejected");
+}
+
+#[tokio::test]
+async fn test_export_csv_via_accept_header() {
+    let resp = client()
+        .get(format!("{}/operators", &*BASE_URL))
+        .header(header::ACCEPT, "text/csv")
+        .send()
+        .await
+        .expect("GET /operators as CSV failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(
+        resp.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/csv")
+    );
+    let body = resp.text().await.unwrap();
+    assert!(body.starts_with("country,iso2,iso3,name,e212,e164,realm,tadig"));
+}
+
+#[tokio::test]
+async fn test_import_csv_dry_run_reports_rows() {
+    let raw_csv = "country,name,e212,e164,tadig\nRomania,Dry Run Mobile,22698;22697,,ROMDR\n";
+    let resp = client()
+        .post(format!("{}/operators/import", &*BASE_URL))
+        .query(&[("dry_run", "true")])
+        .header(header::CONTENT_TYPE, "text/csv")
+        .body(raw_csv)
+        .send()
+        .await
+        .expect("POST /operators/import as CSV failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: serde_json::Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body["total"].as_u64().unwrap(), 1);
+    assert_eq!(body["committed"].as_bool(), Some(false));
+}
@@ -0,0 +1,197 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::{BASE_URL, client, codes, create_input, operator, state_with};
+use operator_mappings_api::{
+    app_state::{AppState, history::OperatorHistory},
+    operators::{
+        crud_operations::{delete::delete_operator, update::update_operator_by_put},
+        history::{get_operator_history, restore_operator_version},
+    },
+    utils::models::{AuditOperation, CreateOperator, Operator},
+};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+use std::env;
+
+fn irish_operator(name: &str, e212: &str, tadig: &str) -> Operator {
+    Operator {
+        e212: codes(&[e212]),
+        ..operator("IE", name, tadig)
+    }
+}
+
+async fn setup_state() -> AppState {
+    let operators = vec![
+        irish_operator("IrishCom", "27201", "IRL01"),
+        irish_operator("Eir", "27203", "IRL03"),
+    ];
+    let state = state_with(operators);
+    let loaded: Vec<Operator> = state
+        .operators
+        .read()
+        .unwrap()
+        .iter()
+        .map(|op| (**op).clone())
+        .collect();
+    state.history.reconcile(&loaded).await.unwrap();
+    state
+}
+
+fn input(name: &str, e212: &str, tadig: &str) -> CreateOperator {
+    create_input("Ireland", name, e212, tadig)
+}
+
+#[tokio::test]
+async fn test_history_follows_tadig_changes() {
+    let state = setup_state().await;
+    let lineage = state.history.live_lineage("IRL01").unwrap();
+    let update =
+        update_operator_by_put(&state, "IRL01", input("IrishCom 2", "27201", "IRL11"), None)
+            .unwrap();
+    state
+        .history
+        .record(AuditOperation::Put, lineage, Some(&update.updated))
+        .await
+        .unwrap();
+
+    let versions = get_operator_history(&state, "IRL11").unwrap();
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0].version, 1);
+    assert_eq!(versions[0].operation, AuditOperation::Baseline);
+    assert_eq!(versions[1].version, 2);
+    assert_eq!(
+        versions[1].operator.as_ref().unwrap().name.as_deref(),
+        Some("IrishCom 2")
+    );
+    assert_eq!(get_operator_history(&state, "IRL01").unwrap().len(), 2);
+    assert!(get_operator_history(&state, "IRLXX").is_err());
+}
+
+#[tokio::test]
+async fn test_restore_recreates_deleted_operator() {
+    let state = setup_state().await;
+    let lineage = state.history.live_lineage("IRL03").unwrap();
+    delete_operator(&state, "IRL03", None).unwrap();
+    state
+        .history
+        .record(AuditOperation::Delete, lineage, None)
+        .await
+        .unwrap();
+
+    assert!(restore_operator_version(&state, "IRL03", 2).is_err());
+    assert!(restore_operator_version(&state, "IRL03", 9).is_err());
+
+    let restored = restore_operator_version(&state, "IRL03", 1).unwrap();
+    assert!(restored.previous.is_none());
+    assert_eq!(Some(restored.lineage), lineage);
+    assert_eq!(restored.restored.name.as_deref(), Some("Eir"));
+    assert!(
+        state
+            .operators
+            .read()
+            .unwrap()
+            .iter()
+            .any(|op| op.has_tadig("IRL03"))
+    );
+}
+
+#[tokio::test]
+async fn test_snapshot_as_of_excludes_later_changes() {
+    let state = setup_state().await;
+    let before_delete = Utc::now();
+    let lineage = state.history.live_lineage("IRL03").unwrap();
+    state
+        .history
+        .record(AuditOperation::Delete, lineage, None)
+        .await
+        .unwrap();
+
+    let past = state.history.snapshot_as_of(before_delete).unwrap();
+    assert_eq!(past.len(), 2);
+    let now = state.history.snapshot_as_of(Utc::now()).unwrap();
+    assert_eq!(now.len(), 1);
+    let earlier = state
+        .history
+        .snapshot_as_of(before_delete - Duration::hours(1))
+        .unwrap();
+    assert!(earlier.is_empty());
+}
+
+#[tokio::test]
+async fn test_reconcile_skips_unchanged_operators() {
+    let path = env::temp_dir().join(format!("operator_history_{}.jsonl", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    let _ = std::fs::remove_file(&path);
+    let operators = vec![irish_operator("IrishCom", "27201", "IRL01")];
+
+    let history = OperatorHistory::open(path.clone()).await.unwrap();
+    history.reconcile(&operators).await.unwrap();
+
+    let reopened = OperatorHistory::open(path.clone()).await.unwrap();
+    reopened.reconcile(&operators).await.unwrap();
+    assert_eq!(reopened.entries.read().unwrap().len(), 1);
+
+    let changed = vec![irish_operator("IrishCom Renamed", "27201", "IRL01")];
+    reopened.reconcile(&changed).await.unwrap();
+    let versions = reopened.versions_for("IRL01").unwrap();
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[1].version, 2);
+
+    reopened.reconcile(&[]).await.unwrap();
+    assert!(reopened.snapshot_as_of(Utc::now()).unwrap().is_empty());
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_history_and_restore_endpoints() {
+    let body = json!({
+        "country": "Ireland",
+        "name":    "Versioned Operator",
+        "e212":    ["27298"],
+        "tadig":   ["IRLVH"]
+    });
+    let resp = client()
+        .post(format!("{}/operators", &*BASE_URL))
+        .json(&body)
+        .send()
+        .await
+        .expect("POST /operators failed");
+    assert!(
+        resp.status().is_success() || resp.status() == StatusCode::CONFLICT,
+        "IRLVH setup failed: {}",
+        resp.status()
+    );
+
+    let resp = client()
+        .patch(format!("{}/operators/IRLVH", &*BASE_URL))
+        .json(&json!({ "name": "Versioned Operator Renamed" }))
+        .send()
+        .await
+        .expect("PATCH /operators/IRLVH failed");
+    assert!(
+        resp.status().is_success(),
+        "patch failed: {}",
+        resp.status()
+    );
+
+    let resp = client()
+        .get(format!("{}/operators/IRLVH/history", &*BASE_URL))
+        .send()
+        .await
+        .expect("GET history failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let versions: Value = resp.json().await.expect("Invalid JSON");
+    let versions = versions.as_array().unwrap();
+    assert!(versions.len() >= 2);
+
+    let resp = client()
+        .post(format!("{}/operators/IRLVH/restore", &*BASE_URL))
+        .query(&[("version", "1")])
+        .send()
+        .await
+        .expect("POST restore failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body["operator"]["name"], "Versioned Operator");
+}
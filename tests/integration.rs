@@ -171,3 +171,30 @@ async fn test_put_operator_invalid_msisdn() {
         resp.status()
     );
 }
+
+#[tokio::test]
+async fn test_patch_with_mismatched_if_match_returns_412() {
+    ensure_patch_operator_exists().await;
+
+    let resp = client()
+        .get(format!("{}/operators", &*BASE_URL))
+        .query(&[("tadig", "ARGTM")])
+        .send()
+        .await
+        .map_err(|e| panic!("Request failed: {}", e))
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(resp.headers().contains_key("etag"));
+
+    let resp = client()
+        .request(Method::PATCH, format!("{}/operators/ARGTM", &*BASE_URL))
+        .header("If-Match", "\"0000000000000000\"")
+        .json(&json!({ "realm": ["epc.mnc007.mcc722.3gppnetwork.org"] }))
+        .send()
+        .await
+        .map_err(|e| panic!("Request failed: {}", e))
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::PRECONDITION_FAILED);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["error_type"], "PreconditionFailed");
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{BASE_URL, client, codes, operator};
+use operator_mappings_api::{
+    operators::integrity::{check_dataset_integrity, enforce_integrity},
+    utils::{
+        config::IntegrityMode,
+        models::{IntegrityIssueKind, Operator},
+    },
+};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+fn irish_operator(id: u64, iso3: &str, e212: &str, tadig: &str) -> Operator {
+    Operator {
+        id,
+        iso3: iso3.to_string(),
+        e212: codes(&[e212]),
+        e164: codes(&["353"]),
+        ..operator("IE", &format!("Operator {}", id), tadig)
+    }
+}
+
+#[test]
+fn test_clean_dataset_has_no_issues() {
+    let mut operators = vec![
+        irish_operator(1, "IRL", "27201", "IRL01"),
+        irish_operator(2, "IRL", "27203", "IRL03"),
+    ];
+    operators[1].e164 = Some(vec!["35383".to_string()]);
+
+    let report = check_dataset_integrity(&operators);
+    assert!(report.clean);
+    assert_eq!(report.operators_checked, 2);
+    assert!(enforce_integrity(&report, IntegrityMode::Strict, IntegrityMode::Strict).is_ok());
+}
+
+#[test]
+fn test_integrity_reports_every_rule() {
+    let operators = vec![
+        irish_operator(1, "IRL", "27201", "IRL01"),
+        irish_operator(2, "IRX", "27201", "irl01"),
+        irish_operator(2, "IRL", "27205", "IR-5"),
+    ];
+
+    let report = check_dataset_integrity(&operators);
+    assert!(!report.clean);
+    let kinds: Vec<IntegrityIssueKind> = report.issues.iter().map(|i| i.kind).collect();
+    for expected in [
+        IntegrityIssueKind::DuplicateId,
+        IntegrityIssueKind::DuplicateTadig,
+        IntegrityIssueKind::MalformedTadig,
+        IntegrityIssueKind::DuplicateE212,
+        IntegrityIssueKind::DuplicateE164,
+        IntegrityIssueKind::IsoMismatch,
+    ] {
+        assert!(kinds.contains(&expected), "missing {:?}", expected);
+    }
+
+    let e212 = report
+        .issues
+        .iter()
+        .find(|i| i.kind == IntegrityIssueKind::DuplicateE212)
+        .unwrap();
+    assert_eq!(e212.value, "27201");
+    assert_eq!(e212.operators, vec![1, 2]);
+
+    assert!(enforce_integrity(&report, IntegrityMode::Lenient, IntegrityMode::Strict).is_ok());
+    assert!(enforce_integrity(&report, IntegrityMode::Strict, IntegrityMode::Strict).is_err());
+}
+
+#[tokio::test]
+async fn test_integrity_endpoint_returns_report() {
+    let resp = client()
+        .get(format!("{}/admin/integrity", &*BASE_URL))
+        .send()
+        .await
+        .expect("GET /admin/integrity failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let report: Value = resp.json().await.expect("Invalid JSON");
+    assert!(report["operators_checked"].as_u64().unwrap() > 0);
+    assert!(report["clean"].is_boolean());
+    assert!(report["issues"].is_array());
+}
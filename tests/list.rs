@@ -0,0 +1,213 @@
+mod common;
+
+use common::{BASE_URL, client, codes, operator};
+use operator_mappings_api::{
+    operators::crud_operations::list::{
+        ProjectedOperator, list_operators, page_links, parse_fields, parse_sort,
+    },
+    utils::models::{NameKind, Operator, OperatorName, PageRequest, ReadQuery},
+};
+use reqwest::{StatusCode, header};
+use serde_json::{Value, json};
+
+fn listed(id: u64, iso2: &str, name: &str, tadig: &str, realm: &str) -> Operator {
+    Operator {
+        id,
+        realm: codes(&[realm]),
+        ..operator(iso2, name, tadig)
+    }
+}
+
+fn sample() -> Vec<Operator> {
+    vec![
+        listed(1, "CL", "Movistar Chile", "CHL01", "epc.mnc002.mcc730"),
+        listed(2, "AR", "Claro", "ARG02", "epc.mnc310.mcc722"),
+        listed(3, "AR", "Movistar", "ARG03", "epc.mnc007.mcc722"),
+        listed(4, "BR", "Vivo", "BRA04", "epc.mnc006.mcc724"),
+        listed(5, "AR", "Personal", "ARG05", "epc.mnc034.mcc722"),
+    ]
+}
+
+fn ids(operators: &[&Operator]) -> Vec<u64> {
+    operators.iter().map(|op| op.id).collect()
+}
+
+#[test]
+fn test_list_filters_by_country_code_realm_and_name() {
+    let data = sample();
+    let all: Vec<&Operator> = data.iter().collect();
+
+    let query = ReadQuery {
+        country: Some("argentina".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        ids(&list_operators(&all, &query).unwrap().operators),
+        [2, 3, 5]
+    );
+
+    let query = ReadQuery {
+        iso2: Some("ar".to_string()),
+        name: Some("MOVI".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(ids(&list_operators(&all, &query).unwrap().operators), [3]);
+
+    let query = ReadQuery {
+        iso3: Some("bra".to_string()),
+        realm: Some("EPC.MNC006.MCC724".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(ids(&list_operators(&all, &query).unwrap().operators), [4]);
+}
+
+#[test]
+fn test_list_name_filter_matches_alternative_names() {
+    let mut data = sample();
+    data[3].names = Some(vec![OperatorName {
+        kind: NameKind::Legal,
+        value: "Telefonica Brasil S.A.".to_string(),
+    }]);
+    let all: Vec<&Operator> = data.iter().collect();
+
+    let query = ReadQuery {
+        name: Some("telefonica".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(ids(&list_operators(&all, &query).unwrap().operators), [4]);
+}
+
+#[test]
+fn test_list_sorts_by_multiple_keys() {
+    let data = sample();
+    let all: Vec<&Operator> = data.iter().collect();
+
+    let query = ReadQuery {
+        sort: Some("country,-name".to_string()),
+        ..Default::default()
+    };
+    let listing = list_operators(&all, &query).unwrap();
+    assert_eq!(ids(&listing.operators), [5, 3, 2, 4, 1]);
+
+    assert!(parse_sort("country,-bogus").is_err());
+    assert!(parse_sort("tadig").is_err());
+    assert!(parse_fields("name,bogus").is_err());
+    assert!(parse_fields(" , ").is_err());
+}
+
+#[test]
+fn test_list_paginates_with_cursor_and_pages() {
+    let data = sample();
+    let all: Vec<&Operator> = data.iter().collect();
+
+    let query = ReadQuery {
+        limit: Some(2),
+        cursor: Some("2".to_string()),
+        ..Default::default()
+    };
+    let listing = list_operators(&all, &query).unwrap();
+    assert_eq!(ids(&listing.operators), [3, 4]);
+    assert_eq!(listing.total, 5);
+    assert_eq!(
+        page_links("/api/v1/operators", "limit=2&cursor=2", listing.page, 5),
+        vec![
+            "</api/v1/operators?limit=2&cursor=0>; rel=\"first\"",
+            "</api/v1/operators?limit=2&cursor=0>; rel=\"prev\"",
+            "</api/v1/operators?limit=2&cursor=4>; rel=\"next\"",
+        ]
+    );
+
+    let query = ReadQuery {
+        page: Some(3),
+        per_page: Some(2),
+        ..Default::default()
+    };
+    let listing = list_operators(&all, &query).unwrap();
+    assert_eq!(ids(&listing.operators), [5]);
+    assert_eq!(
+        listing.page,
+        PageRequest::Page {
+            page: 3,
+            per_page: 2
+        }
+    );
+    let links = page_links("/api/v1/operators", "per_page=2&page=3", listing.page, 5);
+    assert!(links.iter().all(|link| !link.contains("rel=\"next\"")));
+    assert!(links.contains(&"</api/v1/operators?per_page=2&page=3>; rel=\"last\"".to_string()));
+
+    let mixed = ReadQuery {
+        limit: Some(2),
+        page: Some(1),
+        ..Default::default()
+    };
+    assert!(list_operators(&all, &mixed).is_err());
+    let oversized = ReadQuery {
+        per_page: Some(5000),
+        ..Default::default()
+    };
+    assert!(list_operators(&all, &oversized).is_err());
+}
+
+#[test]
+fn test_projection_keeps_only_requested_fields() {
+    let data = sample();
+    let fields = parse_fields("name,tadig").unwrap();
+    let projected = ProjectedOperator {
+        operator: &data[0],
+        fields: &fields,
+    };
+    assert_eq!(
+        serde_json::to_value(&projected).unwrap(),
+        json!({ "name": "Movistar Chile", "tadig": ["CHL01"] })
+    );
+}
+
+#[tokio::test]
+async fn test_list_endpoint_pagination_and_projection() {
+    let resp = client()
+        .get(format!("{}/operators", &*BASE_URL))
+        .query(&[
+            ("sort", "-country"),
+            ("fields", "name,tadig"),
+            ("limit", "2"),
+        ])
+        .send()
+        .await
+        .expect("GET /operators failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let total: usize = resp.headers()["x-total-count"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(total > 2);
+    let link = resp.headers()[header::LINK].to_str().unwrap().to_string();
+    assert!(link.contains("cursor=2>; rel=\"next\""), "got `{}`", link);
+
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    let page = body.as_array().unwrap();
+    assert_eq!(page.len(), 2);
+    for op in page {
+        let keys: Vec<&String> = op.as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["name", "tadig"]);
+    }
+
+    let resp = client()
+        .get(format!("{}/operators", &*BASE_URL))
+        .query(&[("iso3", "ROU"), ("name", "romtel")])
+        .send()
+        .await
+        .expect("GET /operators failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["tadig"][0], "ROM05");
+
+    let resp = client()
+        .get(format!("{}/operators", &*BASE_URL))
+        .query(&[("sort", "bogus")])
+        .send()
+        .await
+        .expect("GET /operators failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
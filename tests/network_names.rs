@@ -1,8 +1,19 @@
 use dotenv::dotenv;
 use once_cell::sync::Lazy;
+use operator_mappings_api::{
+    app_state::AppState,
+    operators::{
+        crud_operations::{create::create_operator, update::update_operator_by_patch},
+        network_names::{retrieve_network_names, retrieve_network_names_by_type},
+    },
+    utils::{
+        csv_format::{operators_from_csv, operators_to_csv},
+        models::{CreateOperator, NameKind, Operator, OperatorName, PatchOperator},
+    },
+};
 use reqwest::{Client, StatusCode};
-use serde_json::Value;
-use std::env;
+use serde_json::{Value, json};
+use std::{collections::HashMap, env};
 
 static BASE_URL: Lazy<String> = Lazy::new(|| {
     dotenv().ok();
@@ -64,3 +75,184 @@ async fn test_network_names_empty_array_when_no_operators() {
         names
     );
 }
+
+fn typed(kind: NameKind, value: &str) -> OperatorName {
+    OperatorName {
+        kind,
+        value: value.to_string(),
+    }
+}
+
+fn setup_state() -> AppState {
+    AppState::new(
+        vec![Operator {
+            id: 0,
+            country: "Argentina".to_string(),
+            iso2: "AR".to_string(),
+            iso3: "ARG".to_string(),
+            name: Some("Telefónica Móviles Argentina S.A.".to_string()),
+            names: Some(vec![
+                typed(NameKind::Legal, "Telefónica Móviles Argentina S.A."),
+                typed(NameKind::Brand, "Movistar"),
+                typed(NameKind::Historic, "Unifon"),
+            ]),
+            e212: None,
+            e164: None,
+            realm: None,
+            tadig: Some(vec!["ARGTM".to_string()]),
+        }],
+        "test_mapping.json".to_string(),
+        HashMap::new(),
+    )
+}
+
+fn new_operator(name: &str, names: Vec<OperatorName>) -> CreateOperator {
+    CreateOperator {
+        country: "Argentina".to_string(),
+        e164: None,
+        e212: None,
+        name: Some(name.to_string()),
+        names: Some(names),
+        realm: None,
+        tadig: Some(vec!["ARGCM".to_string()]),
+    }
+}
+
+#[test]
+fn test_network_names_per_type() {
+    let state = setup_state();
+    assert_eq!(
+        retrieve_network_names(&state, Some(NameKind::Brand)).unwrap(),
+        vec!["Movistar"]
+    );
+    assert!(retrieve_network_names(&state, Some(NameKind::Short)).is_err());
+
+    let grouped = retrieve_network_names_by_type(&state).unwrap();
+    assert_eq!(grouped["historic"], vec!["Unifon"]);
+    assert_eq!(grouped["name"], vec!["Telefónica móviles argentina s.a."]);
+    assert!(grouped["short"].is_empty());
+}
+
+#[test]
+fn test_operator_names_unique_across_all_types() {
+    let state = setup_state();
+
+    let err = create_operator(
+        &state,
+        new_operator("Claro", vec![typed(NameKind::Historic, "UNIFON")]),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("already exists"), "got `{}`", err);
+    assert!(create_operator(&state, new_operator("movistar", Vec::new())).is_err());
+    assert!(
+        create_operator(
+            &state,
+            new_operator(
+                "Claro",
+                vec![
+                    typed(NameKind::Brand, "Claro"),
+                    typed(NameKind::Short, "claro")
+                ]
+            )
+        )
+        .is_err()
+    );
+
+    let created = create_operator(
+        &state,
+        new_operator(
+            "AMX Argentina S.A.",
+            vec![
+                typed(NameKind::Legal, "AMX Argentina S.A."),
+                typed(NameKind::Brand, "Claro"),
+                typed(NameKind::Historic, "CTI Móvil"),
+            ],
+        ),
+    )
+    .unwrap();
+    assert_eq!(created.names.as_ref().unwrap().len(), 3);
+
+    let patch = PatchOperator {
+        names: Some(vec![typed(NameKind::Brand, "Movistar")]),
+        ..Default::default()
+    };
+    assert!(update_operator_by_patch(&state, "ARGCM", patch, None).is_err());
+    let patch = PatchOperator {
+        names: Some(vec![typed(NameKind::Brand, "Claro Argentina")]),
+        ..Default::default()
+    };
+    let updated = update_operator_by_patch(&state, "ARGCM", patch, None).unwrap();
+    assert_eq!(
+        updated.updated.names,
+        Some(vec![typed(NameKind::Brand, "Claro Argentina")])
+    );
+}
+
+#[test]
+fn test_typed_names_round_trip_through_csv() {
+    let state = setup_state();
+    let operators = state.operators.read().unwrap();
+    let csv = operators_to_csv(&[&*operators[0]]).unwrap();
+    assert!(csv.contains("legal:Telefónica Móviles Argentina S.A.;brand:Movistar;historic:Unifon"));
+
+    let rows = operators_from_csv(&csv).unwrap();
+    assert_eq!(rows[0].names, operators[0].names);
+    assert!(operators_from_csv("country,names\nArgentina,nickname:Movi\n").is_err());
+}
+
+#[tokio::test]
+async fn test_network_names_by_type_endpoint() {
+    let body = json!({
+        "country": "Uruguay",
+        "name": "Administración Nacional de Telecomunicaciones",
+        "names": [
+            { "kind": "brand", "value": "Antel Uruguay" },
+            { "kind": "short", "value": "ANTEL-UY" }
+        ],
+        "tadig": ["URYAN"]
+    });
+    let resp = client()
+        .post(format!("{}/operators", &*BASE_URL))
+        .json(&body)
+        .send()
+        .await
+        .expect("POST /operators failed");
+    assert!(
+        resp.status() == StatusCode::CREATED || resp.status() == StatusCode::CONFLICT,
+        "operator setup failed: {}",
+        resp.status()
+    );
+
+    let resp = client()
+        .get(format!("{}/operators/network-names", &*BASE_URL))
+        .query(&[("type", "brand")])
+        .send()
+        .await
+        .expect("GET /operators/network-names failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let brands: Vec<String> = resp.json().await.expect("Invalid JSON");
+    assert!(brands.contains(&"Antel uruguay".to_string()));
+
+    let resp = client()
+        .get(format!("{}/operators/network-names", &*BASE_URL))
+        .query(&[("by_type", "true")])
+        .send()
+        .await
+        .expect("GET /operators/network-names failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let grouped: Value = resp.json().await.expect("Invalid JSON");
+    assert!(
+        grouped["short"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("Antel-uy"))
+    );
+
+    let resp = client()
+        .get(format!("{}/operators/network-names", &*BASE_URL))
+        .query(&[("type", "nickname")])
+        .send()
+        .await
+        .expect("GET /operators/network-names failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
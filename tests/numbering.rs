@@ -0,0 +1,305 @@
+mod common;
+
+use common::{BASE_URL, client, codes, operator, state_with};
+use operator_mappings_api::{
+    app_state::AppState,
+    operators::{
+        crud_operations::{
+            create::create_operator, read::lookup_operator_by_query,
+            update::update_operator_by_patch,
+        },
+        integrity::{check_dataset_integrity, enforce_integrity},
+        mcc::list_mncs,
+    },
+    utils::{
+        config::IntegrityMode,
+        models::{
+            CreateOperator, E212Code, IntegrityIssueKind, MatchMode, Operator, PatchOperator,
+            QueryType,
+        },
+        numbering::{
+            calling_code_of, check_calling_code, countries_for_mcc, normalise_msisdn, parse_e212,
+            parse_mcc_table, parse_realm,
+        },
+    },
+};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+fn argentine_operator() -> Operator {
+    Operator {
+        id: 1,
+        e212: codes(&["72207", "722010"]),
+        e164: codes(&["54911"]),
+        realm: codes(&[
+            "epc.mnc007.mcc722.3gppnetwork.org",
+            "epc.mnc010.mcc722.3gppnetwork.org",
+        ]),
+        ..operator("AR", "Telefonica Argentina", "ARGTM")
+    }
+}
+
+fn setup_state() -> AppState {
+    state_with(vec![argentine_operator()])
+}
+
+fn new_operator(country: &str, e212: &[&str], realm: &[&str]) -> CreateOperator {
+    CreateOperator {
+        country: country.to_string(),
+        e164: None,
+        e212: codes(e212),
+        name: Some(format!("{} Mobile", country)),
+        names: None,
+        realm: codes(realm),
+        tadig: codes(&["NEWOP"]),
+    }
+}
+
+#[test]
+fn test_e212_codes_split_into_mcc_and_mnc() {
+    let code = parse_e212("722010").unwrap();
+    assert_eq!((code.mcc.as_str(), code.mnc.as_str()), ("722", "010"));
+    assert_eq!(parse_e212("72207").unwrap().mnc, "07");
+    for bad in ["7220", "7220100", "3368900890017", "72A07", ""] {
+        assert!(parse_e212(bad).is_none(), "{} should not parse", bad);
+    }
+
+    let realm = parse_realm("EPC.MNC007.MCC722.3GPPNETWORK.ORG").unwrap();
+    assert_eq!(
+        realm,
+        E212Code {
+            mcc: "722".to_string(),
+            mnc: "007".to_string()
+        }
+    );
+    assert!(realm.same_network(&parse_e212("72207").unwrap()));
+    assert!(!realm.same_network(&parse_e212("72217").unwrap()));
+    assert!(parse_realm("ims.example.com").is_none());
+}
+
+#[test]
+fn test_bundled_mcc_table() {
+    assert_eq!(countries_for_mcc("722"), ["AR"]);
+    assert_eq!(countries_for_mcc("234"), ["GB"]);
+    assert!(countries_for_mcc("340").contains(&"GP".to_string()));
+    assert!(countries_for_mcc("999").is_empty());
+    assert!(parse_mcc_table("mcc,alpha2\n72,AR\n").is_err());
+}
+
+#[test]
+fn test_create_validates_e212_against_country() {
+    let state = setup_state();
+    let rejected = [
+        new_operator("Chile", &["7300"], &[]),
+        new_operator("Chile", &["7300112345678"], &[]),
+        new_operator("Chile", &["72231"], &[]),
+        new_operator("Chile", &["99901"], &[]),
+        new_operator("Chile", &["73001"], &["epc.mnc002.mcc730.3gppnetwork.org"]),
+        new_operator("Chile", &[], &["epc.mnc001.mcc722.3gppnetwork.org"]),
+    ];
+    for input in rejected {
+        let e212 = input.e212.clone();
+        let err = create_operator(&state, input).unwrap_err();
+        assert!(
+            err.to_string().contains("FieldValidationError"),
+            "{:?}: got `{}`",
+            e212,
+            err
+        );
+    }
+
+    let created = create_operator(
+        &state,
+        new_operator(
+            "Chile",
+            &["73001", "730002"],
+            &[
+                "epc.mnc001.mcc730.3gppnetwork.org",
+                "epc.mnc002.mcc730.3gppnetwork.org",
+                "ims.entel.cl",
+            ],
+        ),
+    )
+    .unwrap();
+    assert_eq!(created.iso2, "CL");
+}
+
+#[test]
+fn test_patch_rechecks_numbering_when_country_changes() {
+    let state = setup_state();
+    let patch = PatchOperator {
+        country: Some("Chile".to_string()),
+        ..Default::default()
+    };
+    assert!(update_operator_by_patch(&state, "ARGTM", patch, None).is_err());
+
+    let patch = PatchOperator {
+        realm: Some(vec!["epc.mnc099.mcc722.3gppnetwork.org".to_string()]),
+        ..Default::default()
+    };
+    assert!(update_operator_by_patch(&state, "ARGTM", patch, None).is_err());
+
+    let patch = PatchOperator {
+        name: Some(Some("Movistar Argentina".to_string())),
+        ..Default::default()
+    };
+    assert!(update_operator_by_patch(&state, "ARGTM", patch, None).is_ok());
+}
+
+#[test]
+fn test_integrity_reports_numbering_issues() {
+    let mut operator = argentine_operator();
+    operator.e212 = Some(vec!["72207".to_string(), "3368900890017".to_string()]);
+    operator.realm = Some(vec!["epc.mnc010.mcc722.3gppnetwork.org".to_string()]);
+    let mut foreign = argentine_operator();
+    foreign.id = 2;
+    foreign.e212 = Some(vec!["27201".to_string()]);
+    foreign.realm = None;
+    foreign.tadig = Some(vec!["ARGXX".to_string()]);
+
+    let report = check_dataset_integrity(&[operator, foreign]);
+    let found: Vec<(IntegrityIssueKind, &str)> = report
+        .issues
+        .iter()
+        .map(|i| (i.kind, i.value.as_str()))
+        .collect();
+    assert!(found.contains(&(IntegrityIssueKind::MalformedE212, "3368900890017")));
+    assert!(found.contains(&(
+        IntegrityIssueKind::NumberingMismatch,
+        "epc.mnc010.mcc722.3gppnetwork.org"
+    )));
+    assert!(found.contains(&(IntegrityIssueKind::NumberingMismatch, "27201")));
+}
+
+#[test]
+fn test_list_mncs_under_mcc() {
+    let state = setup_state();
+    let listing = list_mncs(&state, "722").unwrap();
+    assert_eq!(listing.countries, vec!["AR"]);
+    let mncs: Vec<&str> = listing.mncs.iter().map(|m| m.mnc.as_str()).collect();
+    assert_eq!(mncs, vec!["07", "010"]);
+    assert_eq!(listing.mncs[1].e212, "722010");
+
+    assert!(list_mncs(&state, "730").unwrap().mncs.is_empty());
+    assert!(list_mncs(&state, "999").is_err());
+    assert!(list_mncs(&state, "72").is_err());
+}
+
+#[test]
+fn test_msisdn_queries_are_normalised() {
+    assert_eq!(normalise_msisdn("+54 9 11 5555-0100"), "5491155550100");
+    assert_eq!(normalise_msisdn("0054 (911) 5555.0100"), "5491155550100");
+    assert_eq!(normalise_msisdn(" 54911 "), "54911");
+
+    let state = setup_state();
+    for query in ["+54 9 11 5555-0100", "005491155550100", "54 911 5555 0100"] {
+        let found = lookup_operator_by_query(&state, query, QueryType::Msisdn, MatchMode::Strict)
+            .unwrap()
+            .unwrap_or_else(|| panic!("{} did not match", query));
+        assert_eq!(found.operator.primary_tadig(), "ARGTM");
+        assert_eq!(found.matched_prefix.as_deref(), Some("54911"));
+    }
+    assert!(
+        lookup_operator_by_query(&state, "+54 9 11 abc", QueryType::Msisdn, MatchMode::Strict)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_e164_prefixes_match_country_calling_code() {
+    assert_eq!(calling_code_of("5491155550100").unwrap().0, "54");
+    assert_eq!(calling_code_of("18765550100").unwrap().1, ["JM"]);
+    assert_eq!(calling_code_of("12125550100").unwrap().1, ["US", "CA"]);
+    assert!(calling_code_of("").is_none());
+
+    assert!(check_calling_code("54911", "AR").is_ok());
+    assert!(check_calling_code("1416", "CA").is_ok());
+    assert!(check_calling_code("1876", "US").is_err());
+    let err = check_calling_code("3368900890017", "AR").unwrap_err();
+    assert!(err.contains("+33 (FR)"), "got `{}`", err);
+}
+
+#[test]
+fn test_e164_mode_decides_between_warning_and_error() {
+    let input = || CreateOperator {
+        e164: Some(vec!["3368900890017".to_string()]),
+        ..new_operator("Chile", &["73001"], &[])
+    };
+
+    let mut state = setup_state();
+    state.e164_validation_mode = IntegrityMode::Strict;
+    let err = create_operator(&state, input()).unwrap_err();
+    assert!(
+        err.to_string().contains("FieldValidationError"),
+        "got `{}`",
+        err
+    );
+    let patch = PatchOperator {
+        e164: Some(vec!["56911".to_string()]),
+        ..Default::default()
+    };
+    assert!(update_operator_by_patch(&state, "ARGTM", patch, None).is_err());
+
+    let lenient = setup_state();
+    let created = create_operator(&lenient, input()).unwrap();
+    assert_eq!(created.e164, Some(vec!["3368900890017".to_string()]));
+
+    let report = check_dataset_integrity(&[(*created).clone()]);
+    assert!(
+        report.issues.iter().any(
+            |i| i.kind == IntegrityIssueKind::CallingCodeMismatch && i.value == "3368900890017"
+        )
+    );
+    assert!(enforce_integrity(&report, IntegrityMode::Strict, IntegrityMode::Lenient).is_ok());
+    assert!(enforce_integrity(&report, IntegrityMode::Strict, IntegrityMode::Strict).is_err());
+}
+
+#[tokio::test]
+async fn test_msisdn_lookup_accepts_international_formats() {
+    for msisdn in ["+40 770 123 456", "0040770123456", "40-770-123-456"] {
+        let resp = client()
+            .get(format!("{}/operators", &*BASE_URL))
+            .query(&[("msisdn", msisdn)])
+            .send()
+            .await
+            .expect("GET /operators failed");
+        assert_eq!(resp.status(), StatusCode::OK, "{}", msisdn);
+        let body: Value = resp.json().await.expect("Invalid JSON");
+        assert_eq!(body["tadig"][0], "ROM05");
+        assert_eq!(body["matched_prefix"], "40770");
+    }
+}
+
+#[tokio::test]
+async fn test_list_mncs_endpoint() {
+    let resp = client()
+        .get(format!("{}/mcc/226/mncs", &*BASE_URL))
+        .send()
+        .await
+        .expect("GET /mcc/226/mncs failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body["countries"][0], "RO");
+    let romanian = body["mncs"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|m| m["e212"] == "22605")
+        .expect("ROM05 MNC missing");
+    assert_eq!(romanian["mnc"], "05");
+    assert_eq!(romanian["operator"]["tadig"][0], "ROM05");
+
+    let resp = client()
+        .get(format!("{}/mcc/999/mncs", &*BASE_URL))
+        .send()
+        .await
+        .expect("GET /mcc/999/mncs failed");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let resp = client()
+        .get(format!("{}/mcc/7a2/mncs", &*BASE_URL))
+        .send()
+        .await
+        .expect("GET /mcc/7a2/mncs failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
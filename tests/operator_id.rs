@@ -0,0 +1,155 @@
+mod common;
+
+use common::{BASE_URL, client, operator, state_with};
+use operator_mappings_api::{
+    app_state::{AppState, model::backfill_operator_ids},
+    operators::crud_operations::{
+        create::create_operator,
+        delete::delete_operator,
+        read::find_operator_by_id,
+        update::{update_operator_by_patch, update_operator_by_put},
+    },
+    utils::models::{CreateOperator, Operator, PatchOperator},
+};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+fn irish_operator(id: u64, name: &str, tadig: &str) -> Operator {
+    Operator {
+        id,
+        ..operator("IE", name, tadig)
+    }
+}
+
+fn setup_state() -> AppState {
+    state_with(vec![
+        irish_operator(7, "IrishCom", "IRL01"),
+        irish_operator(0, "Eir", "IRL03"),
+    ])
+}
+
+#[test]
+fn test_backfill_keeps_existing_ids_and_fills_gaps() {
+    let mut operators = vec![
+        irish_operator(0, "A", "IRLAA"),
+        irish_operator(4, "B", "IRLBB"),
+        irish_operator(0, "C", "IRLCC"),
+    ];
+
+    assert_eq!(backfill_operator_ids(&mut operators), 2);
+    let ids: Vec<u64> = operators.iter().map(|op| op.id).collect();
+    assert_eq!(ids, vec![5, 4, 6]);
+    assert_eq!(backfill_operator_ids(&mut operators), 0);
+}
+
+#[test]
+fn test_id_survives_tadig_replacement() {
+    let state = setup_state();
+    let patch = PatchOperator {
+        tadig: Some(vec!["IRL77".to_string()]),
+        ..Default::default()
+    };
+
+    let update = update_operator_by_patch(&state, 7, patch, None).unwrap();
+    assert_eq!(update.updated.id, 7);
+    assert!(update.updated.has_tadig("IRL77"));
+
+    let put_data = CreateOperator {
+        country: "Ireland".to_string(),
+        e164: None,
+        e212: None,
+        name: Some("IrishCom Renamed".to_string()),
+        names: None,
+        realm: None,
+        tadig: Some(vec!["IRL78".to_string()]),
+    };
+    let update = update_operator_by_put(&state, 7, put_data, None).unwrap();
+    assert_eq!(update.updated.id, 7);
+    assert_eq!(
+        find_operator_by_id(&state, 7).unwrap().name.as_deref(),
+        Some("IrishCom Renamed")
+    );
+}
+
+#[test]
+fn test_new_operators_never_reuse_ids() {
+    let state = setup_state();
+    let eir_id = {
+        let ops = state.operators.read().unwrap();
+        ops.iter().find(|op| op.has_tadig("IRL03")).unwrap().id
+    };
+    assert_eq!(eir_id, 8);
+
+    delete_operator(&state, eir_id, None).unwrap();
+    assert!(find_operator_by_id(&state, eir_id).is_err());
+
+    let created = create_operator(
+        &state,
+        CreateOperator {
+            country: "Ireland".to_string(),
+            e164: None,
+            e212: None,
+            name: Some("Three".to_string()),
+            names: None,
+            realm: None,
+            tadig: Some(vec!["IRLH3".to_string()]),
+        },
+    )
+    .unwrap();
+    assert_eq!(created.id, 9);
+}
+
+#[tokio::test]
+async fn test_by_id_routes_follow_operator_after_tadig_change() {
+    let body = json!({
+        "country": "Ireland",
+        "name":    "Identified Operator",
+        "e212":    ["27297"],
+        "tadig":   ["IRLID"]
+    });
+    let resp = client()
+        .post(format!("{}/operators", &*BASE_URL))
+        .json(&body)
+        .send()
+        .await
+        .expect("POST /operators failed");
+    assert!(
+        resp.status().is_success() || resp.status() == StatusCode::CONFLICT,
+        "IRLID setup failed: {}",
+        resp.status()
+    );
+
+    let resp = client()
+        .get(format!("{}/operators", &*BASE_URL))
+        .query(&[("tadig", "IRLID")])
+        .send()
+        .await
+        .expect("GET /operators failed");
+    let found: Value = resp.json().await.expect("Invalid JSON");
+    let id = found["id"].as_u64().expect("operator must carry an id");
+
+    let resp = client()
+        .patch(format!("{}/operators/by-id/{}", &*BASE_URL, id))
+        .json(&json!({ "tadig": ["IRLI2"] }))
+        .send()
+        .await
+        .expect("PATCH by id failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let resp = client()
+        .get(format!("{}/operators/by-id/{}", &*BASE_URL, id))
+        .send()
+        .await
+        .expect("GET by id failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(resp.headers().contains_key("etag"));
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body["tadig"], json!(["IRLI2"]));
+
+    let resp = client()
+        .get(format!("{}/operators/by-id/{}", &*BASE_URL, u64::MAX))
+        .send()
+        .await
+        .expect("GET by id failed");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
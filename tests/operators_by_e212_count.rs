@@ -1,8 +1,16 @@
 use dotenv::dotenv;
 use once_cell::sync::Lazy;
+use operator_mappings_api::{
+    app_state::AppState,
+    operators::get_operators_by_e212_count::get_operators_grouped_by_size,
+    utils::{
+        config::{Config, SizeBasis, default_size_tiers, parse_size_tiers},
+        models::{Operator, SizeQuery},
+    },
+};
 use reqwest::{Client, StatusCode};
 use serde_json::Value;
-use std::env;
+use std::{collections::HashMap, env};
 
 static BASE_URL: Lazy<String> = Lazy::new(|| {
     dotenv().ok();
@@ -70,3 +78,136 @@ async fn test_grouped_by_e212_schema() {
     assert!(body.get("medium_operators").unwrap().is_array());
     assert!(body.get("large_operators").unwrap().is_array());
 }
+
+fn operator(iso3: &str, tadig: &str, e212: usize, e164: usize) -> Operator {
+    let codes =
+        |base: usize, count: usize| Some((0..count).map(|i| (base + i).to_string()).collect());
+    Operator {
+        id: 0,
+        country: iso3.to_string(),
+        iso2: iso3[..2].to_string(),
+        iso3: iso3.to_string(),
+        name: Some(tadig.to_string()),
+        names: None,
+        e212: codes(72200, e212),
+        e164: codes(5490, e164),
+        realm: None,
+        tadig: Some(vec![tadig.to_string()]),
+    }
+}
+
+fn setup_state() -> AppState {
+    AppState::new(
+        vec![
+            operator("ARG", "ARG01", 1, 9),
+            operator("ARG", "ARG02", 5, 0),
+            operator("CHL", "CHL01", 9, 2),
+        ],
+        "test_mapping.json".to_string(),
+        HashMap::new(),
+    )
+}
+
+fn names(groups: &Value, key: &str) -> Vec<String> {
+    groups[key]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|op| op["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_parse_size_tiers() {
+    assert_eq!(
+        parse_size_tiers("small:3, medium:7, large").unwrap(),
+        default_size_tiers()
+    );
+    assert_eq!(parse_size_tiers("tiny:1,Huge").unwrap().len(), 2);
+    assert!(parse_size_tiers("").is_err());
+    assert!(parse_size_tiers("small:3,large:9").is_err());
+    assert!(parse_size_tiers("small:7,medium:3,large").is_err());
+    assert!(parse_size_tiers("small:3,small").is_err());
+    assert!(parse_size_tiers("small,large").is_err());
+    assert!(parse_size_tiers("small:x,large").is_err());
+}
+
+#[test]
+fn test_group_by_configured_tiers_and_basis() {
+    let state = setup_state();
+    let cfg = Config {
+        size_tiers: parse_size_tiers("single:1,few:4,many").unwrap(),
+        size_weight_e164: 0.5,
+        ..Config::default()
+    };
+
+    let by_e212 = serde_json::to_value(
+        get_operators_grouped_by_size(&state, &cfg, &SizeQuery::default()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(by_e212["basis"], "e212");
+    assert_eq!(names(&by_e212, "single_operators"), vec!["ARG01"]);
+    assert_eq!(names(&by_e212, "few_operators"), Vec::<String>::new());
+    assert_eq!(names(&by_e212, "many_operators"), vec!["ARG02", "CHL01"]);
+    assert_eq!(by_e212["many_size_operators"], 2);
+
+    let query = SizeQuery {
+        basis: Some(SizeBasis::E164),
+        ..SizeQuery::default()
+    };
+    let by_e164 =
+        serde_json::to_value(get_operators_grouped_by_size(&state, &cfg, &query).unwrap()).unwrap();
+    assert_eq!(names(&by_e164, "single_operators"), vec!["ARG02"]);
+    assert_eq!(names(&by_e164, "few_operators"), vec!["CHL01"]);
+    assert_eq!(names(&by_e164, "many_operators"), vec!["ARG01"]);
+
+    let query = SizeQuery {
+        basis: Some(SizeBasis::Weighted),
+        ..SizeQuery::default()
+    };
+    let weighted = get_operators_grouped_by_size(&state, &cfg, &query).unwrap();
+    let counts: Vec<usize> = weighted.tiers.iter().map(|t| t.operators).collect();
+    assert_eq!(counts, vec![0, 0, 3]);
+}
+
+#[test]
+fn test_group_summary_only_and_iso3_filter() {
+    let state = setup_state();
+    let query = SizeQuery {
+        summary_only: true,
+        iso3: Some("arg".to_string()),
+        ..SizeQuery::default()
+    };
+    let groups = serde_json::to_value(
+        get_operators_grouped_by_size(&state, &Config::default(), &query).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(groups["iso3"], "ARG");
+    assert_eq!(groups["small_size_operators"], 1);
+    assert_eq!(groups["medium_size_operators"], 1);
+    assert_eq!(groups["large_size_operators"], 0);
+    assert!(groups.get("small_operators").is_none());
+
+    let unknown = SizeQuery {
+        iso3: Some("ZZZ".to_string()),
+        ..SizeQuery::default()
+    };
+    assert!(get_operators_grouped_by_size(&state, &Config::default(), &unknown).is_err());
+}
+
+#[tokio::test]
+async fn test_grouped_by_e212_summary_only() {
+    let resp = client()
+        .get(format!("{}/operators/grouped-by-e212", &*BASE_URL))
+        .query(&[("summary_only", "true"), ("basis", "e164"), ("iso3", "ROU")])
+        .send()
+        .await
+        .expect("GET /operators/grouped-by-e212 failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body["basis"], "e164");
+    assert_eq!(body["small_size_operators"], 1);
+    assert!(body.get("small_operators").is_none());
+    assert_eq!(body["tiers"].as_array().unwrap().len(), 3);
+}
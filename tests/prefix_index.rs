@@ -0,0 +1,146 @@
+mod common;
+
+use common::{codes, create_input, operator, state_with};
+use operator_mappings_api::{
+    app_state::AppState,
+    operators::crud_operations::{
+        create::create_operator, delete::delete_operator, read::lookup_operator_by_query,
+    },
+    utils::models::{CreateOperator, MatchMode, Operator, QueryType},
+};
+
+fn numbered(iso2: &str, e212: &[&str], e164: &[&str], tadig: &str) -> Operator {
+    Operator {
+        e212: codes(e212),
+        e164: codes(e164),
+        ..operator(iso2, &format!("{} Mobile", tadig), tadig)
+    }
+}
+
+fn setup_state() -> AppState {
+    state_with(vec![
+        numbered("AR", &["72207", "722010"], &["5407"], "ARGTM"),
+        numbered("AR", &["72234"], &["54079"], "ARGCM"),
+        numbered("RO", &["22605"], &["40770", "4077000"], "ROM05"),
+    ])
+}
+
+fn lookup_tadig(state: &AppState, query: &str, query_type: QueryType) -> Option<String> {
+    lookup_operator_by_query(state, query, query_type, MatchMode::Fuzzy)
+        .expect("lookup must not fail")
+        .and_then(|found| {
+            found
+                .operator
+                .tadig
+                .as_ref()
+                .and_then(|t| t.first().cloned())
+        })
+}
+
+#[test]
+fn test_imsi_lookup_picks_longest_prefix() {
+    let state = setup_state();
+
+    assert_eq!(
+        lookup_tadig(&state, "722010123456789", QueryType::Imsi).as_deref(),
+        Some("ARGTM")
+    );
+    assert_eq!(
+        lookup_tadig(&state, "722340000000000", QueryType::Imsi).as_deref(),
+        Some("ARGCM")
+    );
+    assert_eq!(
+        lookup_tadig(&state, "226050000000000", QueryType::Imsi).as_deref(),
+        Some("ROM05")
+    );
+    assert_eq!(lookup_tadig(&state, "999999", QueryType::Imsi), None);
+}
+
+#[test]
+fn test_shared_prefix_tie_goes_to_last_operator() {
+    let state = setup_state();
+
+    assert_eq!(
+        lookup_tadig(&state, "722999", QueryType::Imsi).as_deref(),
+        Some("ARGCM")
+    );
+}
+
+#[test]
+fn test_msisdn_lookup_picks_longest_prefix() {
+    let state = setup_state();
+
+    assert_eq!(
+        lookup_tadig(&state, "540791234", QueryType::Msisdn).as_deref(),
+        Some("ARGCM")
+    );
+    assert_eq!(
+        lookup_tadig(&state, "4077000123", QueryType::Msisdn).as_deref(),
+        Some("ROM05")
+    );
+}
+
+#[test]
+fn test_index_follows_create_and_delete() {
+    let state = setup_state();
+
+    let input = CreateOperator {
+        e164: codes(&["40799"]),
+        ..create_input("Romania", "Vodafone Romania", "22610", "ROMMF")
+    };
+    create_operator(&state, input).expect("create must succeed");
+    assert_eq!(
+        lookup_tadig(&state, "226101234", QueryType::Imsi).as_deref(),
+        Some("ROMMF")
+    );
+
+    delete_operator(&state, "ARGTM", None).expect("delete must succeed");
+    assert_eq!(
+        lookup_tadig(&state, "722010123", QueryType::Imsi).as_deref(),
+        Some("ARGCM")
+    );
+    assert_eq!(
+        lookup_tadig(&state, "226101234", QueryType::Imsi).as_deref(),
+        Some("ROMMF")
+    );
+}
+
+#[test]
+fn test_strict_match_requires_whole_prefix() {
+    let state = setup_state();
+
+    let found = lookup_operator_by_query(&state, "722999", QueryType::Imsi, MatchMode::Strict)
+        .expect("lookup must not fail");
+    assert!(found.is_none(), "722 is not a stored prefix");
+
+    let found = lookup_operator_by_query(&state, "722010123", QueryType::Imsi, MatchMode::Strict)
+        .expect("lookup must not fail")
+        .expect("722010 prefixes the IMSI");
+    assert!(found.operator.has_tadig("ARGTM"));
+    assert_eq!(found.matched_prefix.as_deref(), Some("722010"));
+}
+
+#[test]
+fn test_strict_match_prefers_longest_stored_prefix() {
+    let state = setup_state();
+
+    let found =
+        lookup_operator_by_query(&state, "4077000555", QueryType::Msisdn, MatchMode::Strict)
+            .expect("lookup must not fail")
+            .expect("4077000 prefixes the MSISDN");
+    assert!(found.operator.has_tadig("ROM05"));
+    assert_eq!(found.matched_prefix.as_deref(), Some("4077000"));
+
+    let found = lookup_operator_by_query(&state, "4077123", QueryType::Msisdn, MatchMode::Strict)
+        .expect("lookup must not fail");
+    assert!(
+        found.is_none(),
+        "neither 40770 nor 4077000 prefixes 4077123"
+    );
+
+    let found = lookup_operator_by_query(&state, "4077123", QueryType::Msisdn, MatchMode::Fuzzy)
+        .expect("lookup must not fail")
+        .expect("fuzzy matching scores the shared digits");
+    assert!(found.operator.has_tadig("ROM05"));
+    assert_eq!(found.matched_prefix.as_deref(), Some("4077"));
+}
@@ -0,0 +1,234 @@
+mod common;
+
+use actix_web::{
+    test::TestRequest,
+    web::{Data, Json},
+};
+use common::{BASE_URL, client, create_input, operator};
+use operator_mappings_api::{
+    app_state::{
+        AppState,
+        reload::{SourceWatch, reload_with_borders},
+        sqlite_store::SqliteStore,
+        store::OperatorStore,
+    },
+    handlers::create::handle_create_operator,
+};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+use std::{collections::HashMap, env, fs, sync::Arc};
+use tokio::task::JoinSet;
+
+const BORDERS_CSV: &str = "\"country_code\",\"country_name\",\"country_border_code\",\"country_border_name\"\n\"IE\",\"Ireland\",\"GB\",\"United Kingdom\"\n";
+
+fn temp_path(name: &str) -> String {
+    env::temp_dir()
+        .join(format!("reload_{}_{}", std::process::id(), name))
+        .to_string_lossy()
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_reload_swaps_operators_and_borders() {
+    let mappings_path = temp_path("swap.json");
+    let borders_path = temp_path("swap.csv");
+    let state = AppState::new(
+        vec![operator("IE", "IrishCom", "IRL01")],
+        mappings_path.clone(),
+        HashMap::new(),
+    );
+
+    let edited = vec![
+        operator("IE", "IrishCom", "IRL01"),
+        operator("IE", "Eir", "IRL03"),
+    ];
+    fs::write(&mappings_path, serde_json::to_string(&edited).unwrap()).unwrap();
+    fs::write(&borders_path, BORDERS_CSV).unwrap();
+
+    let summary = reload_with_borders(&state, &borders_path).await.unwrap();
+    assert_eq!(summary.operators, 2);
+    assert_eq!(summary.countries_with_borders, 1);
+    assert_eq!(summary.backfilled_ids, 2);
+
+    let ops = state.operators.read().unwrap();
+    assert_eq!(ops.len(), 2);
+    assert!(ops.iter().all(|op| op.id > 1));
+    assert!(state.borders.read().unwrap().contains_key("IE"));
+    let _ = fs::remove_file(&mappings_path);
+    let _ = fs::remove_file(&borders_path);
+}
+
+#[tokio::test]
+async fn test_reload_keeps_api_writes_in_sqlite_store() {
+    let borders_path = temp_path("sqlite.csv");
+    let store = Arc::new(SqliteStore::in_memory().unwrap());
+    store
+        .snapshot(&[operator("IE", "IrishCom", "IRL01")])
+        .unwrap();
+    let state = Data::new(AppState::with_store(
+        store.load().unwrap(),
+        temp_path("sqlite.json"),
+        HashMap::new(),
+        store.clone(),
+    ));
+    fs::write(&borders_path, BORDERS_CSV).unwrap();
+
+    let input = create_input("Ireland", "Eir", "27203", "IRL03");
+    let resp = handle_create_operator(
+        TestRequest::default().to_http_request(),
+        state.clone(),
+        Json(input),
+    )
+    .await
+    .unwrap();
+    assert!(resp.status().is_success());
+
+    let summary = reload_with_borders(&state, &borders_path).await.unwrap();
+    assert_eq!(summary.operators, 2);
+    assert!(
+        state
+            .operators
+            .read()
+            .unwrap()
+            .iter()
+            .any(|op| op.has_tadig("IRL03"))
+    );
+    assert_eq!(store.load().unwrap().len(), 2);
+    let _ = fs::remove_file(&borders_path);
+}
+
+#[tokio::test]
+async fn test_failed_reload_keeps_old_data() {
+    let mappings_path = temp_path("broken.json");
+    let borders_path = temp_path("broken.csv");
+    let state = AppState::new(
+        vec![operator("IE", "IrishCom", "IRL01")],
+        mappings_path.clone(),
+        HashMap::new(),
+    );
+
+    let duplicated = vec![
+        operator("IE", "IrishCom", "IRL01"),
+        operator("IE", "Eir", "IRL01"),
+    ];
+    fs::write(&mappings_path, serde_json::to_string(&duplicated).unwrap()).unwrap();
+    fs::write(&borders_path, BORDERS_CSV).unwrap();
+
+    let err = reload_with_borders(&state, &borders_path)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("TADIG code 'IRL01' is used by 2 operators"),
+        "got `{}`",
+        err
+    );
+
+    fs::write(&mappings_path, "[{\"country\": ").unwrap();
+    assert!(reload_with_borders(&state, &borders_path).await.is_err());
+
+    assert_eq!(state.operators.read().unwrap().len(), 1);
+    assert!(state.borders.read().unwrap().is_empty());
+    let _ = fs::remove_file(&mappings_path);
+    let _ = fs::remove_file(&borders_path);
+}
+
+#[tokio::test]
+async fn test_reload_endpoint_reports_summary() {
+    let resp = client()
+        .post(format!("{}/admin/reload", &*BASE_URL))
+        .send()
+        .await
+        .expect("POST /admin/reload failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert!(body["summary"]["operators"].as_u64().unwrap() > 0);
+    assert!(body["summary"]["countries_with_borders"].as_u64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn test_reload_after_concurrent_patches_keeps_latest_write() {
+    let resp = client()
+        .post(format!("{}/operators", &*BASE_URL))
+        .json(&json!({
+            "country": "Ireland",
+            "name":    "Ordered Writes",
+            "e212":    ["27296"],
+            "tadig":   ["IRLSQ"]
+        }))
+        .send()
+        .await
+        .expect("POST /operators failed");
+    assert!(
+        resp.status().is_success() || resp.status() == StatusCode::CONFLICT,
+        "IRLSQ setup failed: {}",
+        resp.status()
+    );
+
+    let mut patches = JoinSet::new();
+    for round in 0..16 {
+        patches.spawn(async move {
+            client()
+                .patch(format!("{}/operators/IRLSQ", &*BASE_URL))
+                .json(&json!({ "name": format!("Ordered Writes {}", round) }))
+                .send()
+                .await
+                .expect("PATCH /operators/IRLSQ failed")
+                .status()
+        });
+    }
+    while let Some(status) = patches.join_next().await {
+        assert!(status.unwrap().is_success());
+    }
+
+    let current_name = || async {
+        let body: Value = client()
+            .get(format!("{}/operators", &*BASE_URL))
+            .query(&[("tadig", "IRLSQ")])
+            .send()
+            .await
+            .expect("GET /operators failed")
+            .json()
+            .await
+            .expect("Invalid JSON");
+        body["name"].clone()
+    };
+    let before_reload = current_name().await;
+
+    let resp = client()
+        .post(format!("{}/admin/reload", &*BASE_URL))
+        .send()
+        .await
+        .expect("POST /admin/reload failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(current_name().await, before_reload);
+}
+
+#[tokio::test]
+async fn test_store_compaction_does_not_trigger_reload() {
+    let mappings_path = temp_path("watch.json");
+    let state = AppState::new(
+        vec![operator("IE", "IrishCom", "IRL01")],
+        mappings_path.clone(),
+        HashMap::new(),
+    );
+    state
+        .store
+        .snapshot(&[operator("IE", "IrishCom", "IRL01")])
+        .unwrap();
+    let mut watch = SourceWatch::new(vec![mappings_path.clone()]).await;
+
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    state.store.insert(&operator("IE", "Eir", "IRL03")).unwrap();
+    state.store.compact().unwrap();
+    assert!(!watch.changed(&state).await);
+
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    let edited = vec![
+        operator("IE", "IrishCom", "IRL01"),
+        operator("IE", "Three", "IRLH3"),
+    ];
+    fs::write(&mappings_path, serde_json::to_string(&edited).unwrap()).unwrap();
+    assert!(watch.changed(&state).await);
+    let _ = fs::remove_file(&mappings_path);
+}
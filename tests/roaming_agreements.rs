@@ -0,0 +1,222 @@
+mod common;
+
+use chrono::{Duration, NaiveDate, Utc};
+use common::{BASE_URL, client, operator};
+use operator_mappings_api::{
+    app_state::{AppState, agreements::AgreementRegistry, loaders::parse_country_borders},
+    operators::{
+        find_roaming_partners::find_roaming_partners,
+        roaming_agreements::{
+            create_agreement, delete_agreement, get_agreement, list_agreements, replace_agreement,
+        },
+    },
+    utils::models::{
+        AgreementDirection, AgreementQuery, AgreementService, AgreementStatus, AgreementTechnology,
+        ContractFilter, CreateRoamingAgreement,
+    },
+};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+use std::{collections::HashMap, env};
+
+const BORDERS_CSV: &str =
+    "\"country_code\",\"country_name\",\"country_border_code\",\"country_border_name\"
+\"AR\",\"Argentina\",\"CL\",\"Chile\"
+\"AR\",\"Argentina\",\"BR\",\"Brazil\"
+";
+
+fn setup_state() -> AppState {
+    AppState::new(
+        vec![
+            operator("AR", "Movistar Argentina", "ARGTM"),
+            operator("CL", "Entel Chile", "CHL01"),
+            operator("BR", "Vivo Brazil", "BRA01"),
+        ],
+        "test_mapping.json".to_string(),
+        parse_country_borders(BORDERS_CSV).unwrap(),
+    )
+}
+
+fn agreement(home: &str, partner: &str, technology: AgreementTechnology) -> CreateRoamingAgreement {
+    CreateRoamingAgreement {
+        home_tadig: home.to_string(),
+        partner_tadig: partner.to_string(),
+        technology,
+        services: vec![AgreementService::Data, AgreementService::Voice],
+        status: AgreementStatus::Active,
+        direction: AgreementDirection::Bilateral,
+        valid_from: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        valid_until: None,
+    }
+}
+
+#[tokio::test]
+async fn test_agreement_crud_and_validation() {
+    let state = setup_state();
+
+    let created = create_agreement(
+        &state,
+        agreement("argtm", "CHL01", AgreementTechnology::Lte),
+    )
+    .await
+    .unwrap();
+    assert_eq!(created.id, 1);
+    assert_eq!(created.home_tadig, "ARGTM");
+
+    let reversed = agreement("CHL01", "ARGTM", AgreementTechnology::Lte);
+    let err = create_agreement(&state, reversed).await.unwrap_err();
+    assert!(err.to_string().contains("already exists"), "got `{}`", err);
+    assert!(
+        create_agreement(
+            &state,
+            agreement("CHL01", "ARGTM", AgreementTechnology::FiveG)
+        )
+        .await
+        .is_ok()
+    );
+
+    assert!(
+        create_agreement(
+            &state,
+            agreement("ARGTM", "XXX99", AgreementTechnology::Gsm)
+        )
+        .await
+        .is_err()
+    );
+    assert!(
+        create_agreement(
+            &state,
+            agreement("ARGTM", "ARGTM", AgreementTechnology::Gsm)
+        )
+        .await
+        .is_err()
+    );
+    let mut backwards = agreement("ARGTM", "BRA01", AgreementTechnology::Gsm);
+    backwards.valid_until = NaiveDate::from_ymd_opt(2023, 1, 1);
+    assert!(create_agreement(&state, backwards).await.is_err());
+
+    let mut suspended = agreement("ARGTM", "CHL01", AgreementTechnology::Lte);
+    suspended.status = AgreementStatus::Suspended;
+    let replaced = replace_agreement(&state, created.id, suspended)
+        .await
+        .unwrap();
+    assert_eq!(replaced.status, AgreementStatus::Suspended);
+
+    let query = AgreementQuery {
+        tadig: Some("ARGTM".to_string()),
+        status: Some(AgreementStatus::Suspended),
+    };
+    assert_eq!(list_agreements(&state, &query).unwrap().len(), 1);
+
+    delete_agreement(&state, created.id).await.unwrap();
+    assert!(get_agreement(&state, created.id).is_err());
+    assert!(delete_agreement(&state, created.id).await.is_err());
+}
+
+#[tokio::test]
+async fn test_agreements_persist_to_file() {
+    let path = env::temp_dir().join(format!("roaming_agreements_{}.json", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    let _ = std::fs::remove_file(&path);
+
+    let mut state = setup_state();
+    state.agreements = AgreementRegistry::open(path.clone()).await.unwrap();
+    create_agreement(
+        &state,
+        agreement("ARGTM", "BRA01", AgreementTechnology::Gsm),
+    )
+    .await
+    .unwrap();
+
+    let reopened = AgreementRegistry::open(path.clone()).await.unwrap();
+    let agreements = reopened.entries.read().unwrap();
+    assert_eq!(agreements.len(), 1);
+    assert_eq!(agreements[0].partner_tadig, "BRA01");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_roaming_partners_contract_filter() {
+    let state = setup_state();
+    create_agreement(
+        &state,
+        agreement("CHL01", "ARGTM", AgreementTechnology::Lte),
+    )
+    .await
+    .unwrap();
+    let mut expired = agreement("ARGTM", "BRA01", AgreementTechnology::Lte);
+    expired.valid_from = (Utc::now() - Duration::days(30)).date_naive();
+    expired.valid_until = Some((Utc::now() - Duration::days(1)).date_naive());
+    create_agreement(&state, expired).await.unwrap();
+
+    let all = find_roaming_partners(&state, "ARGTM", 1, ContractFilter::All).unwrap();
+    let flags: HashMap<&str, bool> = all
+        .partners
+        .iter()
+        .map(|p| (p.operator.iso2.as_str(), p.contracted))
+        .collect();
+    assert_eq!(flags, HashMap::from([("CL", true), ("BR", false)]));
+
+    let contracted = find_roaming_partners(&state, "ARGTM", 1, ContractFilter::Contracted).unwrap();
+    assert_eq!(contracted.partners.len(), 1);
+    assert_eq!(contracted.partners[0].operator.iso2, "CL");
+
+    let open = find_roaming_partners(&state, "ARGTM", 1, ContractFilter::Uncontracted).unwrap();
+    assert_eq!(open.partners.len(), 1);
+    assert_eq!(open.partners[0].operator.iso2, "BR");
+}
+
+#[tokio::test]
+async fn test_roaming_agreement_endpoints() {
+    let body = json!({
+        "home_tadig":    "ARGTM",
+        "partner_tadig": "URY01",
+        "technology":    "5g",
+        "services":      ["data"],
+        "status":        "active",
+        "direction":     "outbound",
+        "valid_from":    "2024-01-01"
+    });
+    let resp = client()
+        .post(format!("{}/roaming-agreements", &*BASE_URL))
+        .json(&body)
+        .send()
+        .await
+        .expect("POST /roaming-agreements failed");
+    assert!(
+        resp.status() == StatusCode::CREATED || resp.status() == StatusCode::CONFLICT,
+        "agreement setup failed: {}",
+        resp.status()
+    );
+
+    let resp = client()
+        .get(format!("{}/roaming-agreements", &*BASE_URL))
+        .query(&[("tadig", "URY01")])
+        .send()
+        .await
+        .expect("GET /roaming-agreements failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let agreements: Value = resp.json().await.expect("Invalid JSON");
+    let agreement = &agreements.as_array().unwrap()[0];
+    assert_eq!(agreement["technology"], "5g");
+    assert_eq!(agreement["direction"], "outbound");
+
+    let resp = client()
+        .get(format!("{}/operators/roaming-partners", &*BASE_URL))
+        .query(&[("tadig", "ARGTM"), ("contract", "contracted")])
+        .send()
+        .await
+        .expect("GET /roaming-partners failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    let partners = body["partners"].as_array().unwrap();
+    assert!(partners.iter().all(|p| p["contracted"] == true));
+    assert!(partners.iter().any(|p| p["iso2"] == "UY"));
+
+    let resp = client()
+        .get(format!("{}/roaming-agreements/999999", &*BASE_URL))
+        .send()
+        .await
+        .expect("GET /roaming-agreements/999999 failed");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
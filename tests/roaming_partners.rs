@@ -1,5 +1,10 @@
 use dotenv::dotenv;
 use once_cell::sync::Lazy;
+use operator_mappings_api::{
+    app_state::{AppState, loaders::parse_country_borders},
+    operators::find_roaming_partners::find_roaming_partners,
+    utils::models::{ContractFilter, Operator},
+};
 use reqwest::{Client, StatusCode};
 use serde_json::{Value, json};
 use std::env;
@@ -16,6 +21,111 @@ fn client() -> Client {
         .unwrap()
 }
 
+const BORDERS_CSV: &str =
+    "\"country_code\",\"country_name\",\"country_border_code\",\"country_border_name\"
+\"NE\",\"Niger\",\"NG\",\"Nigeria\"
+\"NE\",\"Niger\",\"TD\",\"Chad\"
+\"NG\",\"Nigeria\",\"NE\",\"Niger\"
+\"NG\",\"Nigeria\",\"CM\",\"Cameroon\"
+\"TD\",\"Chad\",\"NE\",\"Niger\"
+\"TD\",\"Chad\",\"CM\",\"Cameroon\"
+\"CM\",\"Cameroon\",\"NG\",\"Nigeria\"
+\"CM\",\"Cameroon\",\"GQ\",\"Equatorial Guinea\"
+\"GN\",\"Guinea\",\"GW\",\"Guinea-Bissau\"
+\"AU\",\"Australia\",\"\",\"\"
+";
+
+fn operator(country: &str, iso2: &str, iso3: &str, tadig: &str) -> Operator {
+    Operator {
+        id: 0,
+        country: country.to_string(),
+        iso2: iso2.to_string(),
+        iso3: iso3.to_string(),
+        name: Some(format!("{} Mobile", country)),
+        names: None,
+        e212: None,
+        e164: None,
+        realm: None,
+        tadig: Some(vec![tadig.to_string()]),
+    }
+}
+
+fn border_state() -> AppState {
+    let operators = vec![
+        operator("Niger", "NE", "NER", "NERAI"),
+        operator("Nigeria", "NG", "NGA", "NGAMT"),
+        operator("Chad", "TD", "TCD", "TCDTC"),
+        operator("Guinea", "GN", "GIN", "GINOR"),
+        operator("Guinea-Bissau", "GW", "GNB", "GNBOR"),
+        operator("Equatorial Guinea", "GQ", "GNQ", "GNQGE"),
+        operator("Cameroon", "CM", "CMR", "CMRMT"),
+        operator("Australia", "AU", "AUS", "AUSTA"),
+        operator("Australia", "AU", "AUS", "AUSOP"),
+    ];
+    AppState::new(
+        operators,
+        "test_mapping.json".to_string(),
+        parse_country_borders(BORDERS_CSV).unwrap(),
+    )
+}
+
+#[test]
+fn test_roaming_partners_match_exact_iso2() {
+    let state = border_state();
+
+    let niger = find_roaming_partners(&state, "nerai", 1, ContractFilter::All).unwrap();
+    let mut partners: Vec<&str> = niger
+        .partners
+        .iter()
+        .map(|p| p.operator.iso2.as_str())
+        .collect();
+    partners.sort();
+    assert_eq!(partners, vec!["NG", "TD"]);
+    assert_eq!(niger.bordering_countries, vec!["Nigeria", "Chad"]);
+
+    let guinea = find_roaming_partners(&state, "GINOR", 1, ContractFilter::All).unwrap();
+    assert_eq!(guinea.partners.len(), 1);
+    assert_eq!(guinea.partners[0].operator.iso2, "GW");
+
+    assert!(find_roaming_partners(&state, "GNBOR", 1, ContractFilter::All).is_err());
+}
+
+#[test]
+fn test_roaming_partners_within_max_hops() {
+    let state = border_state();
+
+    let result = find_roaming_partners(&state, "NERAI", 3, ContractFilter::All).unwrap();
+    let hops: Vec<(u32, &str)> = result
+        .partners
+        .iter()
+        .map(|p| (p.hops, p.operator.iso2.as_str()))
+        .collect();
+    assert_eq!(hops, vec![(1, "TD"), (1, "NG"), (2, "CM"), (3, "GQ")]);
+    assert_eq!(result.max_hops, 3);
+    assert_eq!(result.bordering_countries, vec!["Nigeria", "Chad"]);
+    let groups: Vec<(u32, usize)> = result
+        .by_distance
+        .iter()
+        .map(|g| (g.hops, g.partners))
+        .collect();
+    assert_eq!(groups, vec![(1, 2), (2, 1), (3, 1)]);
+
+    assert!(find_roaming_partners(&state, "NERAI", 0, ContractFilter::All).is_err());
+    assert!(find_roaming_partners(&state, "NERAI", 99, ContractFilter::All).is_err());
+}
+
+#[test]
+fn test_island_country_returns_domestic_partners() {
+    let state = border_state();
+
+    let result = find_roaming_partners(&state, "AUSTA", 2, ContractFilter::All).unwrap();
+    assert!(result.bordering_countries.is_empty());
+    assert_eq!(result.partners.len(), 1);
+    assert_eq!(result.partners[0].hops, 0);
+    assert!(result.partners[0].operator.has_tadig("AUSOP"));
+    assert!(result.message.contains("no land borders"));
+}
+
 async fn ensure_operator_exists(tadig: &str) {
     let body = json!({
         "country": "Argentina",
@@ -79,6 +189,45 @@ async fn test_find_roaming_partners_success() {
     assert_eq!(partners.len(), count);
 }
 
+#[tokio::test]
+async fn test_find_roaming_partners_max_hops() {
+    ensure_operator_exists("ARGTM").await;
+    let resp = client()
+        .get(format!("{}/operators/roaming-partners", &*BASE_URL))
+        .query(&[("tadig", "ARGTM"), ("max_hops", "2")])
+        .send()
+        .await
+        .expect("GET /roaming-partners failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body["max_hops"], 2);
+    let groups = body["by_distance"].as_array().unwrap();
+    assert_eq!(groups[0]["hops"], 1);
+    assert_eq!(groups[1]["hops"], 2);
+    assert!(
+        groups[1]["countries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c["iso2"] == "PE")
+    );
+    let hops: Vec<u64> = body["partners"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["hops"].as_u64().unwrap())
+        .collect();
+    assert!(hops.windows(2).all(|w| w[0] <= w[1]));
+
+    let resp = client()
+        .get(format!("{}/operators/roaming-partners", &*BASE_URL))
+        .query(&[("tadig", "ARGTM"), ("max_hops", "0")])
+        .send()
+        .await
+        .expect("GET /roaming-partners failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn test_find_roaming_partners_empty_tadig() {
     let resp = client()
@@ -0,0 +1,140 @@
+mod common;
+
+use common::{BASE_URL, client, codes, operator, state_with};
+use operator_mappings_api::{
+    app_state::{AppState, search_index::edit_distance},
+    operators::{
+        crud_operations::{create::create_operator, delete::delete_operator},
+        search::search_operators,
+    },
+    utils::models::{CreateOperator, Operator, SearchField, SearchMatchKind},
+};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+fn realm_operator(iso2: &str, name: &str, tadig: &str, realm: &str) -> Operator {
+    Operator {
+        realm: codes(&[realm]),
+        ..operator(iso2, name, tadig)
+    }
+}
+
+fn setup_state() -> AppState {
+    state_with(vec![
+        realm_operator(
+            "AR",
+            "Telefónica Móviles Argentina S.A.",
+            "ARGTM",
+            "epc.mnc007.mcc722.3gppnetwork.org",
+        ),
+        realm_operator(
+            "AR",
+            "Claro Argentina",
+            "ARGCM",
+            "epc.mnc310.mcc722.3gppnetwork.org",
+        ),
+        realm_operator(
+            "CL",
+            "Movistar Chile",
+            "CHLMV",
+            "epc.mnc007.mcc730.3gppnetwork.org",
+        ),
+    ])
+}
+
+fn tadigs(state: &AppState, query: &str) -> Vec<String> {
+    search_operators(state, query, None)
+        .unwrap()
+        .results
+        .iter()
+        .map(|r| r.operator.primary_tadig().to_string())
+        .collect()
+}
+
+#[test]
+fn test_edit_distance_respects_limit() {
+    assert_eq!(edit_distance("movistar", "movistar", 2), Some(0));
+    assert_eq!(edit_distance("movstar", "movistar", 2), Some(1));
+    assert_eq!(edit_distance("mvstar", "movistar", 1), None);
+    assert_eq!(edit_distance("a", "abcd", 2), None);
+}
+
+#[test]
+fn test_search_ignores_case_and_diacritics() {
+    let state = setup_state();
+    assert_eq!(tadigs(&state, "TELEFONICA"), vec!["ARGTM"]);
+    assert_eq!(tadigs(&state, "móviles"), vec!["ARGTM"]);
+
+    let response = search_operators(&state, "Telefonica", None).unwrap();
+    let found = &response.results[0].matches[0];
+    assert_eq!(found.field, SearchField::Name);
+    assert_eq!(found.kind, SearchMatchKind::Exact);
+}
+
+#[test]
+fn test_search_tolerates_typos_and_ranks_results() {
+    let state = setup_state();
+    let response = search_operators(&state, "movstar", None).unwrap();
+    assert_eq!(response.results[0].operator.primary_tadig(), "CHLMV");
+    assert_eq!(response.results[0].matches[0].kind, SearchMatchKind::Fuzzy);
+    assert_eq!(response.results[0].matches[0].distance, 1);
+
+    let ranked = tadigs(&state, "claro argentina");
+    assert_eq!(ranked[0], "ARGCM");
+    assert_eq!(ranked.len(), 2);
+
+    let response = search_operators(&state, "argentina", None).unwrap();
+    assert_eq!(response.total, 2);
+    assert!(response.results[0].score >= response.results[1].score);
+}
+
+#[test]
+fn test_search_covers_tadig_realm_and_country() {
+    let state = setup_state();
+    assert_eq!(tadigs(&state, "argcm"), vec!["ARGCM", "ARGTM"]);
+    assert_eq!(tadigs(&state, "mcc730"), vec!["CHLMV"]);
+    assert_eq!(tadigs(&state, "chile")[0], "CHLMV");
+    assert!(tadigs(&state, "zzzzzz").is_empty());
+    assert!(search_operators(&state, " -- ", None).is_err());
+    assert!(search_operators(&state, "claro", Some(0)).is_err());
+}
+
+#[test]
+fn test_search_index_follows_mutations() {
+    let state = setup_state();
+    let input = CreateOperator {
+        country: "Peru".to_string(),
+        e164: None,
+        e212: None,
+        name: Some("Entel Perú".to_string()),
+        names: None,
+        realm: None,
+        tadig: Some(vec!["PERTM".to_string()]),
+    };
+    create_operator(&state, input).unwrap();
+    assert_eq!(tadigs(&state, "entel peru"), vec!["PERTM"]);
+
+    delete_operator(&state, "PERTM", None).unwrap();
+    assert!(tadigs(&state, "entel").is_empty());
+}
+
+#[tokio::test]
+async fn test_search_endpoint() {
+    let resp = client()
+        .get(format!("{}/operators/search", &*BASE_URL))
+        .query(&[("q", "telefonica argentina")])
+        .send()
+        .await
+        .expect("GET /operators/search failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body["results"][0]["operator"]["tadig"][0], "ARGTM");
+    assert!(body["results"][0]["score"].as_f64().unwrap() > 0.0);
+
+    let resp = client()
+        .get(format!("{}/operators/search", &*BASE_URL))
+        .send()
+        .await
+        .expect("GET /operators/search failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,231 @@
+mod common;
+
+use common::{BASE_URL, client, codes, operator, state_with};
+use operator_mappings_api::{
+    app_state::{AppState, steering::SteeringRegistry},
+    operators::steering::{
+        delete_steering_preference, get_steering_preference, list_steering_preferences,
+        set_steering_preference, steer_subscriber,
+    },
+    utils::models::{Operator, SetSteeringPreference, SteeringPartner, SteeringQuery},
+};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+use std::env;
+
+fn network(iso2: &str, tadig: &str, e212: &str) -> Operator {
+    Operator {
+        e212: codes(&[e212]),
+        ..operator(iso2, &format!("{} network", tadig), tadig)
+    }
+}
+
+fn setup_state() -> AppState {
+    state_with(vec![
+        network("AR", "ARGTM", "72207"),
+        network("CL", "CHL01", "73001"),
+        network("CL", "CHL02", "73002"),
+        network("BR", "BRA01", "72406"),
+    ])
+}
+
+fn preference(partners: &[(&str, u32)]) -> SetSteeringPreference {
+    SetSteeringPreference {
+        partners: partners
+            .iter()
+            .map(|(tadig, weight)| SteeringPartner {
+                tadig: tadig.to_string(),
+                weight: *weight,
+            })
+            .collect(),
+    }
+}
+
+#[tokio::test]
+async fn test_set_and_replace_steering_preference() {
+    let state = setup_state();
+
+    let saved = set_steering_preference(
+        &state,
+        "argtm",
+        "chl",
+        preference(&[("chl02", 70), ("CHL01", 30)]),
+    )
+    .await
+    .unwrap();
+    assert_eq!(saved.home_tadig, "ARGTM");
+    assert_eq!(saved.visited_iso3, "CHL");
+    assert_eq!(saved.partners[0].tadig, "CHL02");
+
+    set_steering_preference(&state, "ARGTM", "CHL", preference(&[("CHL01", 100)]))
+        .await
+        .unwrap();
+    let stored = get_steering_preference(&state, "ARGTM", "CHL").unwrap();
+    assert_eq!(
+        stored.partners,
+        vec![SteeringPartner {
+            tadig: "CHL01".to_string(),
+            weight: 100
+        }]
+    );
+
+    let query = SteeringQuery {
+        home: Some("ARGTM".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(list_steering_preferences(&state, &query).unwrap().len(), 1);
+
+    delete_steering_preference(&state, "ARGTM", "CHL")
+        .await
+        .unwrap();
+    assert!(get_steering_preference(&state, "ARGTM", "CHL").is_err());
+}
+
+#[tokio::test]
+async fn test_steering_preference_validation() {
+    let state = setup_state();
+
+    let cases = [
+        ("XXX99", "CHL", preference(&[("CHL01", 1)])),
+        ("ARGTM", "ZZZ", preference(&[("CHL01", 1)])),
+        ("ARGTM", "CHL", preference(&[])),
+        ("ARGTM", "CHL", preference(&[("XXX99", 1)])),
+        ("ARGTM", "CHL", preference(&[("BRA01", 1)])),
+        ("ARGTM", "CHL", preference(&[("CHL01", 1), ("chl01", 2)])),
+    ];
+    for (home, iso3, input) in cases {
+        assert!(
+            set_steering_preference(&state, home, iso3, input)
+                .await
+                .is_err(),
+            "expected {} -> {} to be rejected",
+            home,
+            iso3
+        );
+    }
+    assert!(state.steering.entries.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_steer_resolves_visited_network_from_imsi() {
+    let state = setup_state();
+    set_steering_preference(
+        &state,
+        "ARGTM",
+        "CHL",
+        preference(&[("CHL02", 70), ("CHL01", 30)]),
+    )
+    .await
+    .unwrap();
+
+    let result = steer_subscriber(&state, "argtm", "730011234567890").unwrap();
+    assert_eq!(result.home_tadig, "ARGTM");
+    assert_eq!(result.visited_iso3, "CHL");
+    assert_eq!(result.matched_prefix.as_deref(), Some("73001"));
+    let ranked: Vec<(usize, &str)> = result
+        .partners
+        .iter()
+        .map(|p| (p.rank, p.operator.tadig.as_ref().unwrap()[0].as_str()))
+        .collect();
+    assert_eq!(ranked, vec![(1, "CHL02"), (2, "CHL01")]);
+
+    assert!(steer_subscriber(&state, "ARGTM", "724061234567890").is_err());
+    assert!(steer_subscriber(&state, "ARGTM", "999991234567890").is_err());
+    assert!(steer_subscriber(&state, "XXX99", "730011234567890").is_err());
+}
+
+#[tokio::test]
+async fn test_steering_preference_follows_every_home_tadig() {
+    let home = Operator {
+        tadig: codes(&["ARGTM", "ARGTP"]),
+        ..network("AR", "ARGTM", "72207")
+    };
+    let state = state_with(vec![
+        home,
+        network("CL", "CHL01", "73001"),
+        network("CL", "CHL02", "73002"),
+    ]);
+
+    set_steering_preference(&state, "ARGTM", "CHL", preference(&[("CHL02", 100)]))
+        .await
+        .unwrap();
+    let stored = get_steering_preference(&state, "argtp", "CHL").unwrap();
+    assert_eq!(stored.partners[0].tadig, "CHL02");
+    let result = steer_subscriber(&state, "ARGTP", "730011234567890").unwrap();
+    assert_eq!(result.partners.len(), 1);
+
+    set_steering_preference(&state, "ARGTP", "CHL", preference(&[("CHL01", 100)]))
+        .await
+        .unwrap();
+    let query = SteeringQuery {
+        home: Some("ARGTM".to_string()),
+        ..Default::default()
+    };
+    let listed = list_steering_preferences(&state, &query).unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].partners[0].tadig, "CHL01");
+
+    delete_steering_preference(&state, "ARGTM", "CHL")
+        .await
+        .unwrap();
+    assert!(state.steering.entries.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_steering_preferences_persist_to_file() {
+    let path = env::temp_dir().join(format!("steering_preferences_{}.json", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    let _ = std::fs::remove_file(&path);
+
+    let mut state = setup_state();
+    state.steering = SteeringRegistry::open(path.clone()).await.unwrap();
+    set_steering_preference(&state, "ARGTM", "BRA", preference(&[("BRA01", 1)]))
+        .await
+        .unwrap();
+
+    let reopened = SteeringRegistry::open(path.clone()).await.unwrap();
+    let preferences = reopened.entries.read().unwrap();
+    assert_eq!(preferences.len(), 1);
+    assert_eq!(preferences[0].visited_iso3, "BRA");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_steering_endpoints() {
+    let resp = client()
+        .put(format!("{}/steering/preferences/ROM05/ARG", &*BASE_URL))
+        .json(&json!({ "partners": [{ "tadig": "ARGTM", "weight": 100 }] }))
+        .send()
+        .await
+        .expect("PUT /steering/preferences failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let resp = client()
+        .get(format!("{}/steering", &*BASE_URL))
+        .query(&[("home", "ROM05"), ("imsi", "722070123456789")])
+        .send()
+        .await
+        .expect("GET /steering failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.expect("Invalid JSON");
+    assert_eq!(body["visited_iso3"], "ARG");
+    assert_eq!(body["matched_prefix"], "72207");
+    assert_eq!(body["partners"][0]["rank"], 1);
+    assert_eq!(body["partners"][0]["operator"]["tadig"][0], "ARGTM");
+
+    let resp = client()
+        .get(format!("{}/steering", &*BASE_URL))
+        .query(&[("home", "ROM05"), ("imsi", "226051234567890")])
+        .send()
+        .await
+        .expect("GET /steering failed");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let resp = client()
+        .get(format!("{}/steering", &*BASE_URL))
+        .query(&[("imsi", "722070123456789")])
+        .send()
+        .await
+        .expect("GET /steering failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
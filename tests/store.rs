@@ -0,0 +1,368 @@
+mod common;
+
+use actix_web::{
+    test::TestRequest,
+    web::{Data, Json, Path, Query},
+};
+use common::{codes, create_input, operator};
+use operator_mappings_api::{
+    app_state::{
+        AppState, json_store::JsonFileStore, sqlite_store::SqliteStore, store::OperatorStore,
+    },
+    handlers::{
+        bulk_import::handle_bulk_import, create::handle_create_operator,
+        delete::handle_delete_operator, update::handle_update_by_patch,
+    },
+    utils::models::{ImportQuery, Operator, PatchOperator},
+};
+use std::{collections::HashMap, env, io, sync::Arc};
+use tokio::task::{JoinSet, LocalSet};
+
+fn stored(id: u64, name: &str, tadig: &str) -> Operator {
+    Operator {
+        id,
+        e212: codes(&[&format!("2720{}", id)]),
+        ..operator("IE", name, tadig)
+    }
+}
+
+fn exercise_store(store: &dyn OperatorStore) {
+    store
+        .snapshot(&[stored(1, "IrishCom", "IRL01"), stored(2, "Eir", "IRL03")])
+        .unwrap();
+    assert_eq!(store.load().unwrap().len(), 2);
+
+    store.insert(&stored(3, "Three", "IRLH3")).unwrap();
+    assert_eq!(
+        store.get(3).unwrap().unwrap().name.as_deref(),
+        Some("Three")
+    );
+
+    store
+        .update(&stored(1, "IrishCom Renamed", "IRL01"))
+        .unwrap();
+    assert_eq!(
+        store.get(1).unwrap().unwrap().name.as_deref(),
+        Some("IrishCom Renamed")
+    );
+
+    store.delete(2).unwrap();
+    assert!(store.get(2).unwrap().is_none());
+
+    let ids: Vec<u64> = store.load().unwrap().iter().map(|op| op.id).collect();
+    assert_eq!(ids, vec![1, 3]);
+
+    store
+        .insert_batch(&[stored(4, "Vodafone", "IRLEC"), stored(5, "Tesco", "IRLTM")])
+        .unwrap();
+    let ids: Vec<u64> = store.load().unwrap().iter().map(|op| op.id).collect();
+    assert_eq!(ids, vec![1, 3, 4, 5]);
+}
+
+#[test]
+fn test_json_file_store_round_trip() {
+    let path = env::temp_dir().join(format!("operator_store_{}.json", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    let _ = std::fs::remove_file(&path);
+
+    let store = JsonFileStore::new(path.clone());
+    exercise_store(&store);
+
+    let reopened = JsonFileStore::new(path.clone());
+    assert_eq!(reopened.load().unwrap().len(), 4);
+    let _ = std::fs::remove_file(reopened.journal_path());
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_json_file_store_journals_and_compacts() {
+    let path = env::temp_dir().join(format!("operator_journal_{}.json", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    let store = JsonFileStore::new(path.clone());
+    let _ = std::fs::remove_file(store.journal_path());
+
+    store.snapshot(&[stored(1, "IrishCom", "IRL01")]).unwrap();
+    let snapshot_before = std::fs::read_to_string(&path).unwrap();
+
+    store.insert(&stored(2, "Eir", "IRL03")).unwrap();
+    store
+        .update(&stored(1, "IrishCom Renamed", "IRL01"))
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), snapshot_before);
+    assert_eq!(
+        std::fs::read_to_string(store.journal_path())
+            .unwrap()
+            .lines()
+            .count(),
+        2
+    );
+
+    let mut journal = std::fs::OpenOptions::new()
+        .append(true)
+        .open(store.journal_path())
+        .unwrap();
+    std::io::Write::write_all(&mut journal, b"{\"op\":\"delete\",\"i").unwrap();
+
+    let recovered = JsonFileStore::new(path.clone()).load().unwrap();
+    assert_eq!(recovered.len(), 2);
+    assert_eq!(recovered[0].name.as_deref(), Some("IrishCom Renamed"));
+
+    store.compact().unwrap();
+    assert!(std::fs::metadata(store.journal_path()).is_err());
+    assert_eq!(store.load().unwrap().len(), 2);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_json_file_store_journals_batch_as_one_entry() {
+    let path = env::temp_dir().join(format!("operator_batch_{}.json", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    let store = JsonFileStore::new(path.clone());
+    let _ = std::fs::remove_file(store.journal_path());
+
+    store.snapshot(&[stored(1, "IrishCom", "IRL01")]).unwrap();
+    store
+        .insert_batch(&[stored(2, "Eir", "IRL03"), stored(3, "Three", "IRLH3")])
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(store.journal_path())
+            .unwrap()
+            .lines()
+            .count(),
+        1
+    );
+    assert_eq!(JsonFileStore::new(path.clone()).load().unwrap().len(), 3);
+    let _ = std::fs::remove_file(store.journal_path());
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_sqlite_store_round_trip() {
+    let store = SqliteStore::in_memory().unwrap();
+    exercise_store(&store);
+    assert!(store.update(&stored(42, "Ghost", "IRLGH")).is_err());
+    assert!(store.delete(2).is_err());
+}
+
+#[test]
+fn test_sqlite_store_rejects_batch_as_a_whole() {
+    let store = SqliteStore::in_memory().unwrap();
+    store.snapshot(&[stored(1, "IrishCom", "IRL01")]).unwrap();
+
+    let batch = [stored(2, "Eir", "IRL03"), stored(1, "Clash", "IRLXX")];
+    assert!(store.insert_batch(&batch).is_err());
+    assert_eq!(store.load().unwrap().len(), 1);
+}
+
+#[test]
+fn test_sqlite_store_persists_across_connections() {
+    let path = env::temp_dir().join(format!("operator_store_{}.db", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    let _ = std::fs::remove_file(&path);
+
+    SqliteStore::open(&path)
+        .unwrap()
+        .insert(&stored(5, "Vodafone", "IRLEC"))
+        .unwrap();
+
+    let reopened = SqliteStore::open(&path).unwrap();
+    let loaded = reopened.load().unwrap();
+    assert_eq!(loaded.len(), 1);
+    assert!(loaded[0].has_tadig("IRLEC"));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_concurrent_patches_reach_the_store_in_memory_order() {
+    let path = env::temp_dir().join(format!("operator_ordering_{}.json", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    let store = JsonFileStore::new(path.clone());
+    let _ = std::fs::remove_file(store.journal_path());
+    store.snapshot(&[stored(1, "IrishCom", "IRL01")]).unwrap();
+    let state = Data::new(AppState::new(
+        store.load().unwrap(),
+        path.clone(),
+        HashMap::new(),
+    ));
+
+    let local = LocalSet::new();
+    let mut patches = JoinSet::new();
+    for round in 0..32 {
+        let state = state.clone();
+        patches.spawn_local_on(
+            async move {
+                let patch = PatchOperator {
+                    name: Some(Some(format!("IrishCom {}", round))),
+                    ..Default::default()
+                };
+                handle_update_by_patch(
+                    TestRequest::default().to_http_request(),
+                    state,
+                    Path::from("IRL01".to_string()),
+                    Json(patch),
+                )
+                .await
+                .unwrap()
+                .status()
+            },
+            &local,
+        );
+    }
+    local
+        .run_until(async {
+            while let Some(status) = patches.join_next().await {
+                assert!(status.unwrap().is_success());
+            }
+        })
+        .await;
+
+    let in_memory = state.operators.read().unwrap()[0].name.clone();
+    assert_eq!(store.load().unwrap()[0].name, in_memory);
+    let _ = std::fs::remove_file(store.journal_path());
+    let _ = std::fs::remove_file(&path);
+}
+
+struct FailingStore;
+
+impl OperatorStore for FailingStore {
+    fn load(&self) -> io::Result<Vec<Operator>> {
+        Ok(Vec::new())
+    }
+    fn get(&self, _id: u64) -> io::Result<Option<Operator>> {
+        Ok(None)
+    }
+    fn insert(&self, _operator: &Operator) -> io::Result<()> {
+        Err(io::Error::other("disk full"))
+    }
+    fn insert_batch(&self, _operators: &[Operator]) -> io::Result<()> {
+        Err(io::Error::other("disk full"))
+    }
+    fn update(&self, _operator: &Operator) -> io::Result<()> {
+        Err(io::Error::other("disk full"))
+    }
+    fn delete(&self, _id: u64) -> io::Result<()> {
+        Err(io::Error::other("disk full"))
+    }
+    fn snapshot(&self, _operators: &[Operator]) -> io::Result<()> {
+        Err(io::Error::other("disk full"))
+    }
+}
+
+fn failing_state() -> Data<AppState> {
+    Data::new(AppState::with_store(
+        vec![stored(1, "IrishCom", "IRL01")],
+        "test_mapping.json".to_string(),
+        HashMap::new(),
+        Arc::new(FailingStore),
+    ))
+}
+
+fn stored_tadigs(state: &AppState) -> Vec<String> {
+    state
+        .operators
+        .read()
+        .unwrap()
+        .iter()
+        .map(|op| op.primary_tadig().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_failed_store_write_rolls_back_create() {
+    let state = failing_state();
+    let input = create_input("Ireland", "Eir", "27203", "IRL03");
+
+    let result = handle_create_operator(
+        TestRequest::default().to_http_request(),
+        state.clone(),
+        Json(input),
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(stored_tadigs(&state), ["IRL01"]);
+    assert!(
+        state
+            .prefix_index
+            .read()
+            .unwrap()
+            .e212
+            .longest_prefix("27203")
+            .is_none()
+    );
+    assert!(state.audit_log.entries.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_store_write_rolls_back_delete() {
+    let state = failing_state();
+
+    let result = handle_delete_operator(
+        TestRequest::default().to_http_request(),
+        state.clone(),
+        Path::from("IRL01".to_string()),
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(stored_tadigs(&state), ["IRL01"]);
+    assert!(
+        state
+            .prefix_index
+            .read()
+            .unwrap()
+            .e212
+            .longest_prefix("27201")
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn test_failed_store_write_rolls_back_patch() {
+    let state = failing_state();
+    let patch = PatchOperator {
+        name: Some(Some("IrishCom Renamed".to_string())),
+        ..Default::default()
+    };
+
+    let result = handle_update_by_patch(
+        TestRequest::default().to_http_request(),
+        state.clone(),
+        Path::from("IRL01".to_string()),
+        Json(patch),
+    )
+    .await;
+
+    assert!(result.is_err());
+    let operators = state.operators.read().unwrap();
+    assert_eq!(operators[0].name.as_deref(), Some("IrishCom"));
+}
+
+#[tokio::test]
+async fn test_failed_store_write_rolls_back_import() {
+    let state = failing_state();
+    let rows = vec![
+        create_input("Ireland", "Eir", "27203", "IRL03"),
+        create_input("Ireland", "Three", "27205", "IRLH3"),
+    ];
+
+    let result = handle_bulk_import(
+        TestRequest::default().to_http_request(),
+        state.clone(),
+        Query(ImportQuery { dry_run: false }),
+        Json(rows),
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(stored_tadigs(&state), ["IRL01"]);
+    assert!(
+        state
+            .prefix_index
+            .read()
+            .unwrap()
+            .e212
+            .longest_prefix("27205")
+            .is_none()
+    );
+}
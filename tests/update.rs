@@ -8,20 +8,24 @@ use std::collections::HashMap;
 fn initial_operators() -> Vec<Operator> {
     vec![
         Operator {
+            id: 0,
             country: "Ireland".to_string(),
             iso2: "IE".to_string(),
             iso3: "IRL".to_string(),
             name: Some("IrishCom".to_string()),
+            names: None,
             e212: Some(vec!["27201".to_string()]),
             e164: Some(vec!["35385".to_string()]),
             realm: None,
             tadig: Some(vec!["IRL01".to_string()]),
         },
         Operator {
+            id: 0,
             country: "Romania".to_string(),
             iso2: "RO".to_string(),
             iso3: "ROU".to_string(),
             name: Some("RomNet".to_string()),
+            names: None,
             e212: Some(vec!["22605".to_string()]),
             e164: Some(vec!["40770".to_string()]),
             realm: None,
@@ -48,7 +52,7 @@ fn test_patch_update_name_and_msisdn() {
         ..Default::default()
     };
 
-    let result = update_operator_by_patch(&state, "IRL01", patch);
+    let result = update_operator_by_patch(&state, "IRL01", patch, None);
     assert!(
         result.is_ok(),
         "expected successful patch, got {:?}",
@@ -74,7 +78,7 @@ fn test_patch_invalid_imsi_error() {
         ..Default::default()
     };
 
-    let result = update_operator_by_patch(&state, "IRL01", patch);
+    let result = update_operator_by_patch(&state, "IRL01", patch, None);
     assert!(result.is_err(), "expected error for invalid IMSI");
     let err_string = result.unwrap_err().to_string();
     assert!(
@@ -93,7 +97,7 @@ fn test_patch_duplicate_msisdn_error() {
         ..Default::default()
     };
 
-    let result = update_operator_by_patch(&state, "IRL01", patch);
+    let result = update_operator_by_patch(&state, "IRL01", patch, None);
     assert!(result.is_err(), "expected error for duplicate MSISDN");
     let err_string = result.unwrap_err().to_string();
     assert!(
@@ -112,7 +116,7 @@ fn test_patch_duplicate_tadig_error() {
         ..Default::default()
     };
 
-    let result = update_operator_by_patch(&state, "IRL01", patch);
+    let result = update_operator_by_patch(&state, "IRL01", patch, None);
     assert!(result.is_err(), "expected error for duplicate TADIG");
     let err_string = result.unwrap_err().to_string();
     assert!(
@@ -129,13 +133,14 @@ fn test_put_replaces_operator_successfully() {
     let put_data = CreateOperator {
         country: "Romania".to_string(),
         name: Some("Patched RomNet".to_string()),
+        names: None,
         e212: Some(vec!["22699".to_string()]),
         e164: Some(vec!["40771".to_string()]),
         realm: None,
         tadig: Some(vec!["ROU99".to_string()]),
     };
 
-    let result = update_operator_by_put(&state, "ROM05", put_data);
+    let result = update_operator_by_put(&state, "ROM05", put_data, None);
     assert!(
         result.is_ok(),
         "expected successful PUT, got {:?}",
@@ -165,13 +170,14 @@ fn test_put_invalid_msisdn_error() {
     let bad_data = CreateOperator {
         country: "Romania".to_string(),
         name: Some("X".to_string()),
+        names: None,
         e212: Some(vec!["22605".to_string()]),
         e164: Some(vec!["4077A".to_string()]),
         realm: None,
         tadig: Some(vec!["VALID01".to_string()]),
     };
 
-    let result = update_operator_by_put(&state, "ROM05", bad_data);
+    let result = update_operator_by_put(&state, "ROM05", bad_data, None);
     assert!(result.is_err(), "expected error for invalid MSISDN");
     let err_string = result.unwrap_err().to_string();
     assert!(
@@ -188,13 +194,14 @@ fn test_put_duplicate_imsi_error() {
     let dup_imsi = CreateOperator {
         country: "Ireland".to_string(),
         name: Some("Copycat".to_string()),
+        names: None,
         e212: Some(vec!["27201".to_string()]),
         e164: Some(vec!["40100".to_string()]),
         realm: None,
         tadig: Some(vec!["NEW01".to_string()]),
     };
 
-    let result = update_operator_by_put(&state, "ROM05", dup_imsi);
+    let result = update_operator_by_put(&state, "ROM05", dup_imsi, None);
     assert!(result.is_err(), "expected error for duplicate IMSI");
     let err_string = result.unwrap_err().to_string();
     assert!(
@@ -211,13 +218,14 @@ fn test_put_duplicate_tadig_error() {
     let dup_tadig = CreateOperator {
         country: "Ireland".to_string(),
         name: Some("TadigClash".to_string()),
+        names: None,
         e212: Some(vec!["50101".to_string()]),
         e164: Some(vec!["50500".to_string()]),
         realm: None,
         tadig: Some(vec!["IRL01".to_string()]),
     };
 
-    let result = update_operator_by_put(&state, "ROM05", dup_tadig);
+    let result = update_operator_by_put(&state, "ROM05", dup_tadig, None);
     assert!(result.is_err(), "expected error for duplicate TADIG");
     let err_string = result.unwrap_err().to_string();
     assert!(
@@ -226,3 +234,100 @@ fn test_put_duplicate_tadig_error() {
         err_string
     );
 }
+
+#[test]
+fn test_patch_with_stale_etag_is_rejected() {
+    let state = setup_state();
+    let stale_etag = "\"0000000000000000\"".to_string();
+
+    let patch = PatchOperator {
+        name: Some(Some("Concurrent IrishCom".to_string())),
+        ..Default::default()
+    };
+    let result = update_operator_by_patch(&state, "IRL01", patch, Some(&stale_etag));
+    let err_string = result.unwrap_err().to_string();
+    assert!(
+        err_string.contains("Precondition"),
+        "expected PreconditionFailed, got `{}`",
+        err_string
+    );
+
+    let current_etag = {
+        let ops = state.operators.read().unwrap();
+        let current = ops.iter().find(|o| o.has_tadig("IRL01")).unwrap();
+        assert_eq!(current.name.as_deref(), Some("IrishCom"));
+        current.etag()
+    };
+    let patch = PatchOperator {
+        name: Some(Some("Concurrent IrishCom".to_string())),
+        ..Default::default()
+    };
+    let update = update_operator_by_patch(&state, "IRL01", patch, Some(&current_etag)).unwrap();
+    assert_ne!(update.updated.etag(), current_etag);
+}
+
+#[test]
+fn test_put_honours_wildcard_if_match() {
+    let state = setup_state();
+
+    let put_data = CreateOperator {
+        country: "Romania".to_string(),
+        name: Some("RomNet".to_string()),
+        names: None,
+        e212: Some(vec!["22605".to_string()]),
+        e164: Some(vec!["40770".to_string()]),
+        realm: None,
+        tadig: Some(vec!["ROM05".to_string()]),
+    };
+
+    let result = update_operator_by_put(&state, "ROM05", put_data, Some("*"));
+    assert!(result.is_ok(), "expected success, got {:?}", result.err());
+}
+
+#[test]
+fn test_rejected_patch_leaves_stored_operator_untouched() {
+    let state = setup_state();
+    let before = state.operators.read().unwrap()[0].clone();
+
+    let patch = PatchOperator {
+        name: Some(Some("Renamed IrishCom".to_string())),
+        tadig: Some(vec!["ROM05".to_string()]),
+        ..Default::default()
+    };
+    assert!(update_operator_by_patch(&state, "IRL01", patch, None).is_err());
+
+    let after = state.operators.read().unwrap()[0].clone();
+    assert!(std::sync::Arc::ptr_eq(&before, &after));
+    assert_eq!(after.name.as_deref(), Some("IrishCom"));
+
+    let put_data = CreateOperator {
+        country: "Ireland".to_string(),
+        name: Some("IrishCom".to_string()),
+        names: None,
+        e212: Some(vec!["27201".to_string()]),
+        e164: Some(vec!["35385".to_string()]),
+        realm: None,
+        tadig: Some(vec!["IRL01".to_string()]),
+    };
+    let result = update_operator_by_put(&state, "IRL01", put_data, Some(&before.etag()));
+    assert!(result.is_ok(), "expected success, got {:?}", result.err());
+}
+
+#[test]
+fn test_weak_etag_does_not_satisfy_if_match() {
+    let state = setup_state();
+    let weak_etag = format!("W/{}", state.operators.read().unwrap()[0].etag());
+
+    let patch = PatchOperator {
+        name: Some(Some("Weakly Matched IrishCom".to_string())),
+        ..Default::default()
+    };
+    let err_string = update_operator_by_patch(&state, "IRL01", patch, Some(&weak_etag))
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err_string.contains("Precondition"),
+        "expected PreconditionFailed, got `{}`",
+        err_string
+    );
+}